@@ -1,27 +1,35 @@
 use std::{fs, path::Path};
 
 // use aleph_client::AccountId;
-use anyhow::Result;
-use ark_ed_on_bls12_381::{EdwardsAffine as JubJubAffine, EdwardsProjective as JubJub};
+use aleph_client::sp_core::hashing::blake2_256;
+use anyhow::{anyhow, Result};
+use ark_ed_on_bls12_381::EdwardsProjective as JubJub;
 use ark_serialize::CanonicalDeserialize;
-use ark_std::{vec::Vec, One, Zero};
+use ark_std::{vec::Vec, One};
+use chacha20poly1305::{
+    aead::{Aead, KeyInit},
+    ChaCha20Poly1305, Key, Nonce,
+};
 use ink_primitives::AccountId;
 use liminal_ark_relations::{
     disputes::{
         ecdh::{Ecdh, EcdhScheme},
         field_to_vote, hash_to_field, make_shared_key_hash, make_two_to_one_hash, vote_to_filed,
-        VerdictNegativeRelationWithFullInput, VerdictNoneRelationWithFullInput,
-        VerdictPositiveRelationWithFullInput, VerdictRelation, VoteRelationWithFullInput,
-        MAX_VOTES_LEN,
+        VerdictNegativeRelationWithFullInput, VerdictNegativeRelationWithPublicInput,
+        VerdictNoneRelationWithFullInput, VerdictNoneRelationWithPublicInput,
+        VerdictPositiveRelationWithFullInput, VerdictPositiveRelationWithPublicInput,
+        VerdictRelation, VoteRelationWithFullInput, VoteRelationWithPublicInput, MAX_VOTES_LEN,
     },
-    environment::{CircuitField, Groth16, ProvingSystem},
+    environment::{CircuitField, Groth16, NonUniversalProvingSystem, ProvingSystem},
     serialization::serialize,
     ConstraintSynthesizer,
 };
+use scrypt::{scrypt, Params};
 
 pub mod helpers;
 
-pub const MAJORITY_OF_VOTES: f32 = 0.70; // 70%
+const NONCE_LEN: usize = 12;
+const PASSPHRASE_SALT_LEN: usize = 16;
 
 pub struct PublicVote {
     pub id: AccountId,
@@ -29,6 +37,23 @@ pub struct PublicVote {
     pub hashed_vote: [u64; 4],
 }
 
+/// Verdict and its proofs, the arguments of the contract 'issue_the_verdict'.
+pub struct VerdictInputs {
+    pub verdict: VerdictRelation,
+    pub hashed_votes: [u64; 4],
+    pub proof: Vec<u8>,
+    pub jurors_banned: Vec<AccountId>,
+    pub minority_proof: Vec<u8>,
+}
+
+/// Vote decrypted by the judge.
+struct DecodedVote {
+    id: AccountId,
+    hashed_vote: [u64; 4],
+    vote: u8,
+    hashed_shared_key: [u64; 4],
+}
+
 pub fn generate_proof(
     circuit: impl ConstraintSynthesizer<CircuitField>,
     proving_key_file: &Path,
@@ -38,6 +63,109 @@ pub fn generate_proof(
     Ok(serialize(&Groth16::prove(&pk, circuit)))
 }
 
+/// Verifies the proof locally, against the verifying key from 'verifying_key_file'.
+pub fn verify_proof(
+    proof: &[u8],
+    public_input: Vec<CircuitField>,
+    verifying_key_file: &Path,
+) -> Result<()> {
+    let vk_bytes = fs::read(verifying_key_file)?;
+    let vk = <<Groth16 as ProvingSystem>::VerifyingKey>::deserialize(&*vk_bytes)?;
+    let proof = <<Groth16 as ProvingSystem>::Proof>::deserialize(proof)?;
+    match Groth16::verify(&vk, &public_input, &proof) {
+        Ok(true) => Ok(()),
+        _ => Err(anyhow!(
+            "Proof doesn't match the verifying key {verifying_key_file:?}!"
+        )),
+    }
+}
+
+/// Public input of the vote relation, the same as the contract verifies the proof with.
+pub fn vote_public_input(
+    encrypted_vote: [u64; 4],
+    votes_hash: [u64; 4],
+    new_encrypted_all_votes: [u64; 4],
+) -> Vec<CircuitField> {
+    VoteRelationWithPublicInput::new(encrypted_vote, votes_hash, new_encrypted_all_votes)
+        .serialize_public_input()
+}
+
+/// Public input of the verdict relation, the same as the contract verifies the proof with.
+pub fn verdict_public_input(
+    verdict: VerdictRelation,
+    votes_minimum: u8,
+    votes_maximum: u8,
+    hashed_votes: [u64; 4],
+) -> Vec<CircuitField> {
+    match verdict {
+        VerdictRelation::Positive => VerdictPositiveRelationWithPublicInput::new(
+            votes_minimum,
+            VerdictRelation::Positive as u8,
+            hashed_votes,
+        )
+        .serialize_public_input(),
+        VerdictRelation::Negative => VerdictNegativeRelationWithPublicInput::new(
+            votes_maximum,
+            VerdictRelation::Negative as u8,
+            hashed_votes,
+        )
+        .serialize_public_input(),
+        VerdictRelation::None => VerdictNoneRelationWithPublicInput::new(
+            votes_minimum,
+            votes_maximum,
+            VerdictRelation::None as u8,
+            hashed_votes,
+        )
+        .serialize_public_input(),
+    }
+}
+
+/// Runs the Groth16 setup for the circuit, returns serialized verifying and proving keys.
+/// The setup randomness is a fixed seed of 'liminal_ark_relations', so its toxic waste is
+/// known to anybody and the keys are suitable only for the development chains.
+fn generate_keys(circuit: impl ConstraintSynthesizer<CircuitField>) -> (Vec<u8>, Vec<u8>) {
+    let (vk, pk) = Groth16::generate_keys(circuit);
+    (serialize(&vk), serialize(&pk))
+}
+
+/// Generates verifying and proving keys of the vote relation. Setup depends only
+/// on the circuit shape, so the inputs are zeroed.
+pub fn generate_vote_keys() -> (Vec<u8>, Vec<u8>) {
+    generate_keys(VoteRelationWithFullInput::new(
+        [0u64; 4], [0u64; 4], [0u64; 4], 0u8, [0u64; 4],
+    ))
+}
+
+/// Generates verifying and proving keys of the verdict relation.
+pub fn generate_verdict_keys(verdict: VerdictRelation) -> (Vec<u8>, Vec<u8>) {
+    let decoded_votes = vec![0u8; MAX_VOTES_LEN as usize];
+    let shared_keys = vec![[0u64; 4]; MAX_VOTES_LEN as usize];
+    match verdict {
+        VerdictRelation::Positive => generate_keys(VerdictPositiveRelationWithFullInput::new(
+            0u8,
+            VerdictRelation::Positive as u8,
+            [0u64; 4],
+            decoded_votes,
+            shared_keys,
+        )),
+        VerdictRelation::Negative => generate_keys(VerdictNegativeRelationWithFullInput::new(
+            0u8,
+            VerdictRelation::Negative as u8,
+            [0u64; 4],
+            decoded_votes,
+            shared_keys,
+        )),
+        VerdictRelation::None => generate_keys(VerdictNoneRelationWithFullInput::new(
+            0u8,
+            0u8,
+            VerdictRelation::None as u8,
+            [0u64; 4],
+            decoded_votes,
+            shared_keys,
+        )),
+    }
+}
+
 pub fn prepare_voting_inputs(
     vote: u8,
     votes_hash: [u64; 4],
@@ -45,6 +173,11 @@ pub fn prepare_voting_inputs(
     juror_private_key: Vec<u8>,
     vote_pk_file: &Path,
 ) -> Result<([u64; 4], [u64; 4], Vec<u8>)> {
+    if vote > 1 {
+        return Err(anyhow!(
+            "Invalid vote {vote}, abstain in the private voting mode is public and has no proof!"
+        ));
+    }
     let judge_pub_key = Ecdh::<JubJub>::deserialize_public_key(judge_public_key);
     let juror_priv_key = Ecdh::<JubJub>::deserialize_private_key(juror_private_key);
     let shared_key = Ecdh::<JubJub>::make_shared_key(judge_pub_key, juror_priv_key);
@@ -63,109 +196,416 @@ pub fn prepare_voting_inputs(
     Ok((encrypted_vote.0 .0, new_encrypted_all_votes.0 .0, proof))
 }
 
+/// Decrypt the votes, make the verdict and its proofs. `votes_minimum` and `votes_maximum`
+/// are the verdict thresholds of the dispute, returned by the contract 'get_verdict_thresholds'.
+/// Juries who voted against the verdict are banned, the verdict is proven separately for
+/// them and for the rest of the votes.
 pub fn prepare_counting_inputs(
     judge_private_key: Vec<u8>,
     votes: Vec<PublicVote>,
+    votes_minimum: u8,
+    votes_maximum: u8,
     verdict_none_pk: &Path,
     verdict_negative_pk: &Path,
     verdict_positive_pk: &Path,
-) -> Result<(u8, u8, VerdictRelation, [u64; 4], Vec<AccountId>, Vec<u8>)> {
-    let judge_priv_key = Ecdh::<JubJub>::deserialize_private_key(judge_private_key.clone());
+) -> Result<VerdictInputs> {
+    if votes.len() > MAX_VOTES_LEN as usize {
+        return Err(anyhow!(
+            "Unable to count {} votes, the verdict circuit can count up to {} votes!",
+            votes.len(),
+            MAX_VOTES_LEN
+        ));
+    }
+    let judge_priv_key = Ecdh::<JubJub>::deserialize_private_key(judge_private_key);
 
-    let mut jurors_banned = Vec::<AccountId>::new();
-    let mut decoded_votes = Vec::<u8>::new();
-    let mut shared_keys = Vec::<[u64; 4]>::new();
-    let mut all_votes_hashed = hash_to_field([1u64; 4]);
-    let mut hashed_votes = CircuitField::one();
-    let mut sum_votes: u8 = 0;
-    for i in 0..MAX_VOTES_LEN as usize {
-        if let Some(vote) = votes.get(i) {
-            let juror_pub_key = Ecdh::<JubJub>::deserialize_public_key(vote.pub_key.clone());
+    let decoded_votes: Vec<DecodedVote> = votes
+        .into_iter()
+        .map(|vote| {
+            let juror_pub_key = Ecdh::<JubJub>::deserialize_public_key(vote.pub_key);
             let shared_key = Ecdh::<JubJub>::make_shared_key(juror_pub_key, judge_priv_key);
             let hashed_shared_key = make_shared_key_hash(shared_key);
-            shared_keys.push(hashed_shared_key.0 .0);
-
-            let decode_vote =
-                field_to_vote(hash_to_field(vote.hashed_vote) - hashed_shared_key.clone());
-            hashed_votes = make_two_to_one_hash(hash_to_field(vote.hashed_vote), hashed_votes);
-            all_votes_hashed =
-                make_two_to_one_hash(hash_to_field(vote.hashed_vote), all_votes_hashed);
-            sum_votes += decode_vote;
-            jurors_banned.push(vote.id.clone());
-            decoded_votes.push(decode_vote);
-        } else {
-            let shared_key = Ecdh::<JubJub>::make_shared_key(JubJubAffine::zero(), judge_priv_key);
-            let hashed_shared_key = make_shared_key_hash(shared_key);
+            DecodedVote {
+                id: vote.id,
+                hashed_vote: vote.hashed_vote,
+                vote: field_to_vote(hash_to_field(vote.hashed_vote) - hashed_shared_key),
+                hashed_shared_key: hashed_shared_key.0 .0,
+            }
+        })
+        .collect();
+    let sum_votes: u8 = decoded_votes.iter().map(|vote| vote.vote).sum();
 
-            decoded_votes.push(0u8);
-            shared_keys.push(hashed_shared_key.0 .0);
-            hashed_votes =
-                make_two_to_one_hash(vote_to_filed(0u8) + hashed_shared_key.clone(), hashed_votes);
-        }
-    }
-
-    let votes_minimum: u8 = (MAJORITY_OF_VOTES * votes.len() as f32).ceil() as u8;
-    let votes_maximum: u8 = votes.len() as u8 - votes_minimum;
-    let verdict = if sum_votes >= votes_minimum {
-        jurors_banned = jurors_banned
-            .iter()
-            .enumerate()
-            .filter(|&(index, _)| decoded_votes[index] == 0)
-            .map(|(_, &ref value)| value.clone())
-            .collect();
+    // Verdict for one of the parties needs at least one vote, the contract rejects it otherwise.
+    let verdict = if decoded_votes.is_empty() {
+        VerdictRelation::None
+    } else if sum_votes >= votes_minimum {
         VerdictRelation::Positive
     } else if sum_votes <= votes_maximum {
-        jurors_banned = jurors_banned
-            .iter()
-            .enumerate()
-            .filter(|&(index, _)| decoded_votes[index] == 1)
-            .map(|(_, &ref value)| value.clone())
-            .collect();
         VerdictRelation::Negative
     } else {
         VerdictRelation::None
     };
 
-    let proof = match verdict {
+    let verdict_pk = |verdict: &VerdictRelation| match verdict {
+        VerdictRelation::Positive => verdict_positive_pk,
+        VerdictRelation::Negative => verdict_negative_pk,
+        VerdictRelation::None => verdict_none_pk,
+    };
+    let all_votes: Vec<&DecodedVote> = decoded_votes.iter().collect();
+    let (hashed_votes, _, _) = verdict_circuit_inputs(&all_votes);
+
+    let (majority_vote, minority_verdict) = match verdict {
+        VerdictRelation::Positive => (1u8, VerdictRelation::Negative),
+        VerdictRelation::Negative => (0u8, VerdictRelation::Positive),
+        VerdictRelation::None => {
+            let proof = prove_verdict(
+                &verdict,
+                votes_minimum,
+                votes_maximum,
+                &all_votes,
+                verdict_none_pk,
+            )?;
+            return Ok(VerdictInputs {
+                verdict,
+                hashed_votes,
+                proof,
+                jurors_banned: Vec::new(),
+                minority_proof: Vec::new(),
+            });
+        }
+    };
+
+    // Votes of the majority are all for the verdict, votes of the minority are all against it.
+    let (majority, minority): (Vec<&DecodedVote>, Vec<&DecodedVote>) = decoded_votes
+        .iter()
+        .partition(|vote| vote.vote == majority_vote);
+    let proof = prove_verdict(
+        &verdict,
+        majority.len() as u8,
+        0,
+        &majority,
+        verdict_pk(&verdict),
+    )?;
+    let minority_proof = prove_verdict(
+        &minority_verdict,
+        minority.len() as u8,
+        0,
+        &minority,
+        verdict_pk(&minority_verdict),
+    )?;
+
+    Ok(VerdictInputs {
+        verdict,
+        hashed_votes,
+        proof,
+        jurors_banned: minority.iter().map(|vote| vote.id).collect(),
+        minority_proof,
+    })
+}
+
+/// Verdict circuit inputs for the votes: hash of the votes, decoded votes and hashed shared
+/// keys. Empty slots are filled with the zero vote and the zero shared key.
+fn verdict_circuit_inputs(votes: &[&DecodedVote]) -> ([u64; 4], Vec<u8>, Vec<[u64; 4]>) {
+    let encrypted_votes: Vec<[u64; 4]> = votes.iter().map(|vote| vote.hashed_vote).collect();
+    let mut decoded_votes = Vec::<u8>::new();
+    let mut shared_keys = Vec::<[u64; 4]>::new();
+    for i in 0..MAX_VOTES_LEN as usize {
+        let vote = votes.get(i);
+        decoded_votes.push(vote.map_or(0u8, |vote| vote.vote));
+        shared_keys.push(vote.map_or([0u64; 4], |vote| vote.hashed_shared_key));
+    }
+    (
+        verdict_votes_hash(&encrypted_votes),
+        decoded_votes,
+        shared_keys,
+    )
+}
+
+/// Hash of the encrypted votes, the public input of the verdict relations. It matches the
+/// contract one, empty slots hold the zero vote.
+pub fn verdict_votes_hash(votes: &[[u64; 4]]) -> [u64; 4] {
+    let mut hashed_votes = CircuitField::one();
+    for i in 0..MAX_VOTES_LEN as usize {
+        let vote = votes
+            .get(i)
+            .map_or(vote_to_filed(0u8), |vote| hash_to_field(*vote));
+        hashed_votes = make_two_to_one_hash(vote, hashed_votes);
+    }
+    hashed_votes.0 .0
+}
+
+/// Proves the verdict for the votes: their sum reaches `votes_minimum` (`Positive`), doesn't
+/// exceed `votes_maximum` (`Negative`) or is between them (`None`).
+fn prove_verdict(
+    verdict: &VerdictRelation,
+    votes_minimum: u8,
+    votes_maximum: u8,
+    votes: &[&DecodedVote],
+    proving_key_file: &Path,
+) -> Result<Vec<u8>> {
+    let (hashed_votes, decoded_votes, shared_keys) = verdict_circuit_inputs(votes);
+    match verdict {
         VerdictRelation::Positive => generate_proof(
             VerdictPositiveRelationWithFullInput::new(
                 votes_minimum,
                 VerdictRelation::Positive as u8,
-                hashed_votes.0 .0,
+                hashed_votes,
                 decoded_votes,
                 shared_keys,
             ),
-            verdict_positive_pk,
-        )?,
+            proving_key_file,
+        ),
         VerdictRelation::Negative => generate_proof(
             VerdictNegativeRelationWithFullInput::new(
                 votes_maximum,
                 VerdictRelation::Negative as u8,
-                hashed_votes.0 .0,
+                hashed_votes,
                 decoded_votes,
                 shared_keys,
             ),
-            verdict_negative_pk,
-        )?,
+            proving_key_file,
+        ),
         VerdictRelation::None => generate_proof(
             VerdictNoneRelationWithFullInput::new(
                 votes_minimum,
                 votes_maximum,
                 VerdictRelation::None as u8,
-                hashed_votes.0 .0,
+                hashed_votes,
                 decoded_votes,
                 shared_keys,
             ),
-            verdict_none_pk,
-        )?,
-    };
+            proving_key_file,
+        ),
+    }
+}
 
+/// Makes the commitment of the vote (commit-reveal voting mode). It matches the
+/// contract one: blake2 hash of the scale encoded `(juror, vote, salt)`.
+pub fn make_vote_commitment(juror: &AccountId, vote: u8, salt: &[u8; 32]) -> [u64; 4] {
+    let mut data = juror.as_ref().to_vec();
+    data.push(vote);
+    data.extend_from_slice(salt);
+    let hash = blake2_256(&data);
+
+    let mut commitment = [0u64; 4];
+    for (limb, chunk) in commitment.iter_mut().zip(hash.chunks(8)) {
+        *limb = u64::from_le_bytes(chunk.try_into().unwrap());
+    }
+    commitment
+}
+
+/// Generates a JubJub key pair, used by the juror / judge in the dispute. Returns
+/// the serialized public and private keys.
+pub fn generate_ecdh_keys() -> (Vec<u8>, Vec<u8>) {
+    let rng = &mut rand::thread_rng();
+    let (public_key, private_key) = Ecdh::<JubJub>::generate_keys(rng);
+    (
+        Ecdh::<JubJub>::serialize_public_key(public_key),
+        Ecdh::<JubJub>::serialize_private_key(private_key),
+    )
+}
+
+/// Encrypts the data with a key derived from the passphrase. Returns the salt,
+/// the nonce and the ciphertext.
+pub fn encrypt_with_passphrase(data: &[u8], passphrase: &str) -> Result<Vec<u8>> {
+    let salt: [u8; PASSPHRASE_SALT_LEN] = rand::random();
+    let cipher = passphrase_cipher(passphrase, &salt)?;
+
+    let nonce: [u8; NONCE_LEN] = rand::random();
+    let mut encrypted = salt.to_vec();
+    encrypted.extend(nonce);
+    encrypted.extend(
+        cipher
+            .encrypt(Nonce::from_slice(&nonce), data)
+            .map_err(|_| anyhow!("Failed to encrypt the data!"))?,
+    );
+    Ok(encrypted)
+}
+
+/// Decrypts the data encrypted by 'encrypt_with_passphrase'.
+pub fn decrypt_with_passphrase(data: &[u8], passphrase: &str) -> Result<Vec<u8>> {
+    if data.len() < PASSPHRASE_SALT_LEN + NONCE_LEN {
+        return Err(anyhow!("Invalid encrypted data!"));
+    }
+    let (salt, data) = data.split_at(PASSPHRASE_SALT_LEN);
+    let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+    passphrase_cipher(passphrase, salt)?
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| anyhow!("Failed to decrypt the data, invalid passphrase!"))
+}
+
+fn passphrase_cipher(passphrase: &str, salt: &[u8]) -> Result<ChaCha20Poly1305> {
+    let mut key = [0u8; 32];
+    scrypt(
+        passphrase.as_bytes(),
+        salt,
+        &Params::recommended(),
+        &mut key,
+    )
+    .map_err(|_| anyhow!("Failed to derive the key from the passphrase!"))?;
+    Ok(ChaCha20Poly1305::new(Key::from_slice(&key)))
+}
+
+/// Encrypts the evidence for each of the jurors public keys. Returns the serialized
+/// ephemeral public key of the author and the encrypted evidence for each juror.
+pub fn encrypt_evidence(
+    evidence: &[u8],
+    jurors_public_keys: Vec<Vec<u8>>,
+) -> Result<(Vec<u8>, Vec<Vec<u8>>)> {
+    let rng = &mut rand::thread_rng();
+    let (author_pub_key, author_priv_key) = Ecdh::<JubJub>::generate_keys(rng);
+
+    let mut encrypted = Vec::<Vec<u8>>::new();
+    for juror_public_key in jurors_public_keys {
+        let juror_pub_key = Ecdh::<JubJub>::deserialize_public_key(juror_public_key);
+        let shared_key = Ecdh::<JubJub>::make_shared_key(juror_pub_key, author_priv_key);
+        let cipher = evidence_cipher(make_shared_key_hash(shared_key).0 .0);
+
+        let nonce: [u8; NONCE_LEN] = rand::random();
+        let mut data = nonce.to_vec();
+        data.extend(
+            cipher
+                .encrypt(Nonce::from_slice(&nonce), evidence)
+                .map_err(|_| anyhow!("Failed to encrypt the evidence!"))?,
+        );
+        encrypted.push(data);
+    }
     Ok((
-        votes_maximum,
-        votes_minimum,
-        verdict,
-        hashed_votes.0 .0,
-        jurors_banned,
-        proof,
+        Ecdh::<JubJub>::serialize_public_key(author_pub_key),
+        encrypted,
     ))
 }
+
+/// Decrypts the evidence with the juror private key and the author public key.
+pub fn decrypt_evidence(
+    author_public_key: Vec<u8>,
+    juror_private_key: Vec<u8>,
+    data: &[u8],
+) -> Result<Vec<u8>> {
+    if data.len() < NONCE_LEN {
+        return Err(anyhow!("Invalid evidence data!"));
+    }
+    let author_pub_key = Ecdh::<JubJub>::deserialize_public_key(author_public_key);
+    let juror_priv_key = Ecdh::<JubJub>::deserialize_private_key(juror_private_key);
+    let shared_key = Ecdh::<JubJub>::make_shared_key(author_pub_key, juror_priv_key);
+    let cipher = evidence_cipher(make_shared_key_hash(shared_key).0 .0);
+
+    let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+    cipher
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| anyhow!("Failed to decrypt the evidence!"))
+}
+
+fn evidence_cipher(hashed_shared_key: [u64; 4]) -> ChaCha20Poly1305 {
+    let key: Vec<u8> = hashed_shared_key
+        .iter()
+        .flat_map(|limb| limb.to_le_bytes())
+        .collect();
+    ChaCha20Poly1305::new(Key::from_slice(&key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_key(name: &str, key: &[u8]) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("bright_disputes_{name}.bytes"));
+        fs::write(&path, key).unwrap();
+        path
+    }
+
+    #[test]
+    fn vote_proof_verifies() {
+        let (judge_pub_key, _) = generate_ecdh_keys();
+        let (_, juror_priv_key) = generate_ecdh_keys();
+        let (vk, pk) = generate_vote_keys();
+        let vk_file = write_key("vote_vk", &vk);
+        let pk_file = write_key("vote_pk", &pk);
+
+        let votes_hash = hash_to_field([1u64; 4]).0 .0;
+        let (encrypted_vote, new_encrypted_all_votes, proof) =
+            prepare_voting_inputs(1, votes_hash, judge_pub_key, juror_priv_key, &pk_file).unwrap();
+        assert!(verify_proof(
+            &proof,
+            vote_public_input(encrypted_vote, votes_hash, new_encrypted_all_votes),
+            &vk_file
+        )
+        .is_ok());
+        assert!(verify_proof(
+            &proof,
+            vote_public_input(encrypted_vote, votes_hash, votes_hash),
+            &vk_file
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn counting_proof_with_padded_slots_verifies() {
+        let (judge_pub_key, judge_priv_key) = generate_ecdh_keys();
+        let votes: Vec<PublicVote> = [1u8, 1u8]
+            .into_iter()
+            .map(|vote| {
+                let (juror_pub_key, juror_priv_key) = generate_ecdh_keys();
+                let shared_key = Ecdh::<JubJub>::make_shared_key(
+                    Ecdh::<JubJub>::deserialize_public_key(judge_pub_key.clone()),
+                    Ecdh::<JubJub>::deserialize_private_key(juror_priv_key),
+                );
+                let encrypted_vote = vote_to_filed(vote) + make_shared_key_hash(shared_key);
+                PublicVote {
+                    id: AccountId::from([vote; 32]),
+                    pub_key: juror_pub_key,
+                    hashed_vote: encrypted_vote.0 .0,
+                }
+            })
+            .collect();
+        assert!(votes.len() < MAX_VOTES_LEN as usize);
+
+        // Same hash of the votes as the contract computes, empty slots hold the zero vote.
+        let mut expected_hashed_votes = CircuitField::one();
+        for i in 0..MAX_VOTES_LEN as usize {
+            let vote = votes
+                .get(i)
+                .map_or(vote_to_filed(0u8), |vote| hash_to_field(vote.hashed_vote));
+            expected_hashed_votes = make_two_to_one_hash(vote, expected_hashed_votes);
+        }
+
+        // Nobody voted against the verdict, the minority has only empty slots.
+        let mut expected_minority_hash = CircuitField::one();
+        for _ in 0..MAX_VOTES_LEN {
+            expected_minority_hash =
+                make_two_to_one_hash(vote_to_filed(0u8), expected_minority_hash);
+        }
+
+        let (positive_vk, positive_pk) = generate_verdict_keys(VerdictRelation::Positive);
+        let positive_vk_file = write_key("padded_slots_verdict_positive_vk", &positive_vk);
+        let positive_pk_file = write_key("padded_slots_verdict_positive_pk", &positive_pk);
+        let (negative_vk, negative_pk) = generate_verdict_keys(VerdictRelation::Negative);
+        let negative_vk_file = write_key("padded_slots_verdict_negative_vk", &negative_vk);
+        let negative_pk_file = write_key("padded_slots_verdict_negative_pk", &negative_pk);
+
+        let inputs = prepare_counting_inputs(
+            judge_priv_key,
+            votes,
+            2,
+            0,
+            &positive_pk_file,
+            &negative_pk_file,
+            &positive_pk_file,
+        )
+        .unwrap();
+        assert!(matches!(inputs.verdict, VerdictRelation::Positive));
+        assert_eq!(inputs.hashed_votes, expected_hashed_votes.0 .0);
+        assert!(inputs.jurors_banned.is_empty());
+        assert!(verify_proof(
+            &inputs.proof,
+            verdict_public_input(inputs.verdict, 2, 0, inputs.hashed_votes),
+            &positive_vk_file
+        )
+        .is_ok());
+        assert!(verify_proof(
+            &inputs.minority_proof,
+            verdict_public_input(VerdictRelation::Negative, 0, 0, expected_minority_hash.0 .0),
+            &negative_vk_file
+        )
+        .is_ok());
+    }
+}
@@ -0,0 +1,183 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use aleph_client::{
+    sp_core::{sr25519, Pair},
+    AccountId, KeyPair,
+};
+use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use inquire::Password;
+use scrypt::{scrypt, Params};
+use serde::{Deserialize, Serialize};
+use xsalsa20poly1305::{aead::Aead, KeyInit, Nonce, XSalsa20Poly1305};
+
+use crate::application::Application;
+
+const SCRYPT_HEADER_LEN: usize = 32 + 3 * 4;
+const NONCE_LEN: usize = 24;
+const PKCS8_HEADER: [u8; 16] = [48, 83, 2, 1, 1, 48, 5, 6, 3, 43, 101, 112, 4, 34, 4, 32];
+const PKCS8_DIVIDER: [u8; 5] = [161, 35, 3, 33, 0];
+const SECRET_KEY_LEN: usize = 64;
+const PUBLIC_KEY_LEN: usize = 32;
+
+/// Source of the account secret, stored under the alias in the application state.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub enum AccountSource {
+    /// Name of the environment variable with the SURI
+    SuriEnv(String),
+    /// File with the SURI
+    SuriFile(PathBuf),
+    /// Polkadot-JS JSON keystore
+    Keystore(PathBuf),
+}
+
+/// Polkadot-JS JSON keystore, as exported by the Polkadot-JS apps.
+#[derive(Deserialize)]
+struct JsonKeystore {
+    encoded: String,
+    encoding: JsonKeystoreEncoding,
+    address: String,
+}
+
+#[derive(Deserialize)]
+struct JsonKeystoreEncoding {
+    content: Vec<String>,
+    #[serde(rename = "type")]
+    kind: Vec<String>,
+    version: String,
+}
+
+/// Get the key pair of the account. 'account' is an alias stored in the application
+/// state, a path to the JSON keystore or a SURI.
+pub fn keypair(app: &Application, account: &str) -> Result<KeyPair> {
+    match account_source(app, account) {
+        Some(AccountSource::SuriEnv(name)) => {
+            let suri = env::var(&name).map_err(|_| anyhow!("Variable {name} is not set!"))?;
+            keypair_from_suri(&suri)
+        }
+        Some(AccountSource::SuriFile(path)) => {
+            let suri =
+                fs::read_to_string(&path).map_err(|e| anyhow!("Failed to load {path:?}: {e}"))?;
+            keypair_from_suri(suri.trim())
+        }
+        Some(AccountSource::Keystore(path)) => keypair_from_keystore(&path),
+        None => keypair_from_suri(account),
+    }
+}
+
+/// Get the account id. Unlike 'keypair', it doesn't need the keystore password and
+/// accepts the SS58 address.
+pub fn account_id(app: &Application, account: &str) -> Result<AccountId> {
+    if let Ok(account_id) = AccountId::from_str(account) {
+        return Ok(account_id);
+    }
+    match account_source(app, account) {
+        Some(AccountSource::Keystore(path)) => {
+            let keystore = load_keystore(&path)?;
+            AccountId::from_str(&keystore.address)
+                .map_err(|_| anyhow!("Invalid address in {path:?}!"))
+        }
+        _ => Ok(keypair(app, account)?.account_id().clone()),
+    }
+}
+
+fn account_source(app: &Application, account: &str) -> Option<AccountSource> {
+    if let Some(source) = app.accounts.get(account) {
+        return Some(source.clone());
+    }
+    let path = Path::new(account);
+    if path.extension().map_or(false, |ext| ext == "json") && path.exists() {
+        return Some(AccountSource::Keystore(path.into()));
+    }
+    None
+}
+
+fn keypair_from_suri(suri: &str) -> Result<KeyPair> {
+    let pair = sr25519::Pair::from_string(suri, None)
+        .map_err(|e| anyhow!("Failed to create the key pair from SURI: {e:?}"))?;
+    Ok(KeyPair::new(pair))
+}
+
+fn load_keystore(path: &Path) -> Result<JsonKeystore> {
+    let content = fs::read(path).map_err(|e| anyhow!("Failed to load {path:?}: {e}"))?;
+    serde_json::from_slice::<JsonKeystore>(&content)
+        .map_err(|e| anyhow!("Failed to deserialize {path:?}: {e}"))
+}
+
+/// Decrypt the sr25519 key pair from the Polkadot-JS JSON keystore (version 3,
+/// scrypt and xsalsa20-poly1305), password is prompted.
+fn keypair_from_keystore(path: &Path) -> Result<KeyPair> {
+    let keystore = load_keystore(path)?;
+    let encoding = &keystore.encoding;
+    if encoding.version != "3"
+        || !encoding.kind.iter().any(|kind| kind == "scrypt")
+        || !encoding.content.iter().any(|content| content == "sr25519")
+    {
+        return Err(anyhow!("Unsupported keystore encoding in {path:?}!"));
+    }
+
+    let encoded = STANDARD
+        .decode(&keystore.encoded)
+        .map_err(|e| anyhow!("Failed to decode {path:?}: {e}"))?;
+    if encoded.len() < SCRYPT_HEADER_LEN + NONCE_LEN {
+        return Err(anyhow!("Invalid keystore {path:?}!"));
+    }
+
+    let password = Password::new(&format!("Password of {}:", keystore.address))
+        .without_confirmation()
+        .prompt()
+        .map_err(|e| anyhow!("Failed to read password: {e}"))?;
+    let decrypted = decrypt_keystore(&encoded, &password)?;
+
+    let secret_end = PKCS8_HEADER.len() + SECRET_KEY_LEN;
+    let public_start = secret_end + PKCS8_DIVIDER.len();
+    if decrypted.len() < public_start + PUBLIC_KEY_LEN
+        || decrypted[..PKCS8_HEADER.len()] != PKCS8_HEADER
+        || decrypted[secret_end..public_start] != PKCS8_DIVIDER
+    {
+        return Err(anyhow!("Invalid keystore content in {path:?}!"));
+    }
+
+    let secret =
+        schnorrkel::SecretKey::from_ed25519_bytes(&decrypted[PKCS8_HEADER.len()..secret_end])
+            .map_err(|_| anyhow!("Invalid secret key in {path:?}!"))?;
+    let pair = sr25519::Pair::from(secret);
+    if pair.public().0[..] != decrypted[public_start..public_start + PUBLIC_KEY_LEN] {
+        return Err(anyhow!(
+            "Public key doesn't match the secret key in {path:?}!"
+        ));
+    }
+    Ok(KeyPair::new(pair))
+}
+
+fn decrypt_keystore(encoded: &[u8], password: &str) -> Result<Vec<u8>> {
+    let (header, encrypted) = encoded.split_at(SCRYPT_HEADER_LEN);
+    let (salt, scrypt_params) = header.split_at(32);
+    let param = |index: usize| {
+        u32::from_le_bytes(
+            scrypt_params[index * 4..(index + 1) * 4]
+                .try_into()
+                .unwrap(),
+        )
+    };
+    let (n, p, r) = (param(0), param(1), param(2));
+    if !n.is_power_of_two() {
+        return Err(anyhow!("Invalid scrypt parameters!"));
+    }
+    let params = Params::new(n.trailing_zeros() as u8, r, p, 32)
+        .map_err(|_| anyhow!("Invalid scrypt parameters!"))?;
+
+    let mut key = [0u8; 32];
+    scrypt(password.as_bytes(), salt, &params, &mut key)
+        .map_err(|_| anyhow!("Failed to derive the key from the password!"))?;
+
+    let (nonce, ciphertext) = encrypted.split_at(NONCE_LEN);
+    XSalsa20Poly1305::new_from_slice(&key)
+        .map_err(|_| anyhow!("Invalid key length!"))?
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| anyhow!("Failed to decrypt the keystore, invalid password!"))
+}
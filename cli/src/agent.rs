@@ -0,0 +1,409 @@
+use std::{collections::HashSet, fs, path::Path, time::Duration};
+
+use aleph_client::{SignedConnection, SignedConnectionApi};
+use anyhow::{anyhow, Result};
+use bright_disputes_lib::generate_ecdh_keys;
+use futures::{
+    channel::mpsc::{self, UnboundedReceiver, UnboundedSender},
+    StreamExt,
+};
+use inquire::Select;
+use serde::{Deserialize, Serialize};
+use tokio::time::interval;
+use tracing::{info, warn};
+
+use crate::{
+    application::Application,
+    bright_disputes::{BrightDisputes, ABSTAIN_VOTE},
+    bright_disputes_ink::{ActionKind, VotingMode},
+    config::OutputFormat,
+    keystore::Keystore,
+    output::{self, CommandOutput},
+    watch::Notice,
+};
+
+/// Policy of the agent, loaded from the JSON file. Missing fields take the default values.
+#[derive(Deserialize, Serialize)]
+#[serde(default)]
+pub struct Policy {
+    /// Confirm participation, when assigned to the dispute as a juror
+    pub confirm_as_juror: bool,
+    /// Confirm participation, when assigned to the dispute as the judge
+    pub confirm_as_judge: bool,
+    /// Maximum escrow, which participation is confirmed for
+    pub max_escrow: Option<u128>,
+    /// Ask for the vote, when requested to vote
+    pub prompt_vote: bool,
+    /// Count the votes as the judge, when the round reaches 'CountingTheVotes'
+    pub count_the_votes: bool,
+    /// Process the dispute round, when the transition is due
+    pub process_dispute_round: bool,
+}
+
+impl Default for Policy {
+    fn default() -> Self {
+        Self {
+            confirm_as_juror: true,
+            confirm_as_judge: true,
+            max_escrow: None,
+            prompt_vote: true,
+            count_the_votes: true,
+            process_dispute_round: true,
+        }
+    }
+}
+
+impl Policy {
+    /// Load the policy from 'path', default policy is used if the path is not given.
+    pub fn load(path: Option<&Path>) -> Result<Self> {
+        let path = match path {
+            Some(path) => path,
+            None => return Ok(Self::default()),
+        };
+        let content = fs::read(path).map_err(|e| anyhow!("Failed to load {path:?}: {e}"))?;
+        serde_json::from_slice::<Policy>(&content)
+            .map_err(|e| anyhow!("Failed to deserialize {path:?}: {e}"))
+    }
+}
+
+/// Vote given by the human in the prompt, 'None' if the vote is skipped.
+struct PromptedVote {
+    dispute_id: u32,
+    commit_reveal: bool,
+    vote: Option<u8>,
+}
+
+/// Takes the pending actions of the account, as allowed by the policy.
+pub struct Agent<'a> {
+    app: &'a Application,
+    bright_disputes: &'a BrightDisputes,
+    connection: SignedConnection,
+    policy: Policy,
+    output_format: OutputFormat,
+    /// Votes asked from the human, they are asked again only if skipped or not submitted
+    prompted: HashSet<u32>,
+    /// Dispute, which vote is being asked. Prompts run off the actions loop, one at a time
+    prompting: Option<u32>,
+    votes_sender: UnboundedSender<PromptedVote>,
+    votes_receiver: Option<UnboundedReceiver<PromptedVote>>,
+    /// Disputes, which escrow exceeds the policy limit, they are reported once
+    escrow_exceeded: HashSet<u32>,
+    /// Keystore unlocked on start, so the passphrase is not asked while the agent runs
+    keystore: Keystore,
+}
+
+impl<'a> Agent<'a> {
+    pub fn new(
+        app: &'a Application,
+        bright_disputes: &'a BrightDisputes,
+        connection: SignedConnection,
+        policy: Policy,
+        output_format: OutputFormat,
+    ) -> Result<Self> {
+        let keystore = Keystore::load(&app.keystore_path)?.unlock()?;
+        let (votes_sender, votes_receiver) = mpsc::unbounded();
+        Ok(Self {
+            app,
+            bright_disputes,
+            connection,
+            policy,
+            output_format,
+            prompted: HashSet::new(),
+            prompting: None,
+            votes_sender,
+            votes_receiver: Some(votes_receiver),
+            escrow_exceeded: HashSet::new(),
+            keystore,
+        })
+    }
+
+    /// Take the pending actions on each notice and every 'period'.
+    pub async fn run(
+        &mut self,
+        mut notices: UnboundedReceiver<Notice>,
+        period: Duration,
+    ) -> Result<()> {
+        let mut votes = self
+            .votes_receiver
+            .take()
+            .ok_or_else(|| anyhow!("Agent is already running!"))?;
+        let mut ticks = interval(period);
+        loop {
+            tokio::select! {
+                notice = notices.next() => {
+                    let notice = notice.ok_or_else(|| anyhow!("Notices sender closed!"))?;
+                    notice.print(self.output_format)?;
+                }
+                vote = votes.next() => {
+                    let vote = vote.ok_or_else(|| anyhow!("Votes sender closed!"))?;
+                    let dispute_id = vote.dispute_id;
+                    self.prompting = None;
+                    // Vote is asked again, if it was skipped or failed.
+                    match self.submit_vote(vote).await {
+                        Ok(Some(result)) => result.print(self.output_format)?,
+                        Ok(None) => {
+                            self.prompted.remove(&dispute_id);
+                        }
+                        Err(e) => {
+                            self.prompted.remove(&dispute_id);
+                            warn!("Failed to vote in the dispute {dispute_id}: {e}");
+                        }
+                    }
+                }
+                _ = ticks.tick() => {}
+            }
+            self.take_pending_actions().await?;
+        }
+    }
+
+    async fn take_pending_actions(&mut self) -> Result<()> {
+        let actions = self
+            .bright_disputes
+            .pending_actions(&self.connection)
+            .await?;
+        for (dispute_id, action, _) in actions {
+            match self.take_action(dispute_id, &action).await {
+                Ok(Some(result)) => result.print(self.output_format)?,
+                Ok(None) => {}
+                Err(e) => warn!(
+                    "Failed to take action {} in the dispute {dispute_id}: {e}",
+                    output::enum_name(&action)
+                ),
+            }
+        }
+        Ok(())
+    }
+
+    /// Take the action, if the policy allows it. Returns 'None' if the action is skipped.
+    async fn take_action(
+        &mut self,
+        dispute_id: u32,
+        action: &ActionKind,
+    ) -> Result<Option<CommandOutput>> {
+        let output = match action {
+            ActionKind::ConfirmJuror() if self.policy.confirm_as_juror => {
+                self.confirm_participation(dispute_id, false).await?
+            }
+            ActionKind::ConfirmJudge() if self.policy.confirm_as_judge => {
+                self.confirm_participation(dispute_id, true).await?
+            }
+            ActionKind::Vote() if self.policy.prompt_vote => {
+                self.prompt_vote(dispute_id).await?;
+                None
+            }
+            ActionKind::RevealVote() => self.reveal_vote(dispute_id).await?,
+            ActionKind::CountTheVotes() if self.policy.count_the_votes => {
+                let private_key = self.private_key(dispute_id)?;
+                let transaction = self
+                    .bright_disputes
+                    .count_the_votes(
+                        &self.connection,
+                        dispute_id,
+                        private_key,
+                        &self.app.verdict_none_pk,
+                        &self.app.verdict_negative_pk,
+                        &self.app.verdict_positive_pk,
+                        false,
+                    )
+                    .await?;
+                Some(
+                    CommandOutput::new(format!("Votes counted: {dispute_id}!"))
+                        .with_dispute_id(dispute_id)
+                        .with_transactions(transaction),
+                )
+            }
+            ActionKind::ProcessDisputeRound() if self.policy.process_dispute_round => {
+                let transaction = self
+                    .bright_disputes
+                    .process_dispute_round(&self.connection, dispute_id)
+                    .await?;
+                let dispute = self
+                    .bright_disputes
+                    .get_dispute(&self.connection, dispute_id)
+                    .await?;
+                Some(
+                    CommandOutput::new(format!("Dispute round processed: {dispute_id}!"))
+                        .with_dispute(&dispute)
+                        .with_transactions([transaction]),
+                )
+            }
+            _ => None,
+        };
+        Ok(output)
+    }
+
+    /// Generate the ECDH keys, confirm participation and store the private one in the keystore.
+    async fn confirm_participation(
+        &mut self,
+        dispute_id: u32,
+        as_judge: bool,
+    ) -> Result<Option<CommandOutput>> {
+        let dispute = self
+            .bright_disputes
+            .get_dispute(&self.connection, dispute_id)
+            .await?;
+        if let Some(max_escrow) = self.policy.max_escrow {
+            if dispute.escrow > max_escrow {
+                if self.escrow_exceeded.insert(dispute_id) {
+                    info!("Escrow of the dispute {dispute_id} exceeds the policy limit, skipped");
+                }
+                return Ok(None);
+            }
+        }
+
+        let (public_key, private_key) = generate_ecdh_keys();
+        let transaction = if as_judge {
+            self.bright_disputes
+                .confirm_judge_participation_in_dispute(
+                    &self.connection,
+                    dispute_id,
+                    dispute.escrow,
+                    public_key,
+                )
+                .await?
+        } else {
+            self.bright_disputes
+                .confirm_juror_participation_in_dispute(
+                    &self.connection,
+                    dispute_id,
+                    dispute.escrow,
+                    public_key,
+                )
+                .await?
+        };
+        self.keystore.reload()?;
+        self.keystore
+            .store(self.connection.account_id(), dispute_id, &private_key)?;
+        Ok(Some(
+            CommandOutput::new(format!(
+                "{} confirmed participation in the dispute: {dispute_id}!",
+                if as_judge { "Judge" } else { "Juror" }
+            ))
+            .with_dispute_id(dispute_id)
+            .with_transactions([transaction]),
+        ))
+    }
+
+    /// Ask the human for the vote, the prompt runs off the actions loop and sends the vote
+    /// back to it. Vote is asked once per dispute, unless it is skipped or not submitted.
+    async fn prompt_vote(&mut self, dispute_id: u32) -> Result<()> {
+        if self.prompting.is_some() || !self.prompted.insert(dispute_id) {
+            return Ok(());
+        }
+        let dispute = match self
+            .bright_disputes
+            .get_dispute(&self.connection, dispute_id)
+            .await
+        {
+            Ok(dispute) => dispute,
+            Err(e) => {
+                self.prompted.remove(&dispute_id);
+                return Err(e);
+            }
+        };
+        info!(
+            "Vote requested in the dispute {dispute_id}, owner: {}, defendant: {}",
+            dispute.owner_link,
+            dispute.defendant_link.clone().unwrap_or_default()
+        );
+
+        let commit_reveal = dispute.voting_mode == VotingMode::CommitReveal();
+        let options = vec![
+            "1 - in favour of the owner",
+            "0 - in favour of the defendant",
+            "2 - abstain",
+        ];
+        let prompt = format!("Vote in the dispute {dispute_id}:");
+        let votes_sender = self.votes_sender.clone();
+        self.prompting = Some(dispute_id);
+        tokio::task::spawn_blocking(move || {
+            let vote = Select::new(&prompt, options)
+                .prompt()
+                .ok()
+                .and_then(|choice| choice[..1].parse().ok());
+            votes_sender.unbounded_send(PromptedVote {
+                dispute_id,
+                commit_reveal,
+                vote,
+            })
+        });
+        Ok(())
+    }
+
+    /// Submit the vote given in the prompt. Committed vote is stored in the keystore with
+    /// its salt, until it is revealed.
+    async fn submit_vote(&mut self, prompted: PromptedVote) -> Result<Option<CommandOutput>> {
+        let dispute_id = prompted.dispute_id;
+        let vote = match prompted.vote {
+            Some(vote) => vote,
+            None => {
+                info!("Vote in the dispute {dispute_id} skipped, it will be asked again");
+                return Ok(None);
+            }
+        };
+
+        let transaction = if prompted.commit_reveal {
+            let (salt, transaction) = self
+                .bright_disputes
+                .commit_vote(&self.connection, dispute_id, vote)
+                .await?;
+            self.keystore.reload()?;
+            self.keystore
+                .store_vote(self.connection.account_id(), dispute_id, vote, &salt)?;
+            Some(transaction)
+        } else if vote == ABSTAIN_VOTE {
+            let transaction = self
+                .bright_disputes
+                .abstain(&self.connection, dispute_id)
+                .await?;
+            Some(transaction)
+        } else {
+            let private_key = self.private_key(dispute_id)?;
+            self.bright_disputes
+                .vote(
+                    &self.connection,
+                    dispute_id,
+                    private_key,
+                    vote,
+                    &self.app.vote_pk,
+                    false,
+                )
+                .await?
+        };
+        Ok(Some(
+            CommandOutput::new(format!("Voted in the dispute: {dispute_id}!"))
+                .with_dispute_id(dispute_id)
+                .with_transactions(transaction),
+        ))
+    }
+
+    /// Reveal the vote committed in the keystore (commit-reveal voting mode).
+    async fn reveal_vote(&mut self, dispute_id: u32) -> Result<Option<CommandOutput>> {
+        self.keystore.reload()?;
+        if !self
+            .keystore
+            .has_committed_vote(self.connection.account_id(), dispute_id)
+        {
+            return Ok(None);
+        }
+        let (vote, salt) = self
+            .keystore
+            .committed_vote(self.connection.account_id(), dispute_id)?;
+        let transaction = self
+            .bright_disputes
+            .reveal_vote(&self.connection, dispute_id, vote, salt)
+            .await?;
+        Ok(Some(
+            CommandOutput::new(format!("Vote revealed in the dispute: {dispute_id}!"))
+                .with_dispute_id(dispute_id)
+                .with_transactions([transaction]),
+        ))
+    }
+
+    /// Get the private key from the keystore, it may be stored by the other command.
+    fn private_key(&mut self, dispute_id: u32) -> Result<Vec<u8>> {
+        self.keystore.reload()?;
+        self.keystore
+            .private_key(self.connection.account_id(), dispute_id)
+    }
+}
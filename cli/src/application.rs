@@ -1,10 +1,13 @@
-use std::{fs, fs::File, path::PathBuf, str};
+use std::{collections::BTreeMap, fs, fs::File, path::PathBuf, str};
 
 use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
 
+use crate::accounts::AccountSource;
+
 /// Application state
 #[derive(Deserialize, Serialize)]
+#[serde(default)]
 pub struct Application {
     pub config_path: PathBuf,
     pub metadata_path: PathBuf,
@@ -14,6 +17,8 @@ pub struct Application {
     pub verdict_none_pk: PathBuf,
     pub verdict_negative_pk: PathBuf,
     pub verdict_positive_pk: PathBuf,
+    pub keystore_path: PathBuf,
+    pub accounts: BTreeMap<String, AccountSource>,
 }
 
 impl Default for Application {
@@ -26,7 +31,9 @@ impl Default for Application {
             vote_pk: "../scripts/docker/keys/vote.groth16.pk.bytes".into(),
             verdict_none_pk: "../scripts/docker/keys/verdict_none.groth16.pk.bytes".into(),
             verdict_negative_pk: "../scripts/docker/keys/verdict_negative.groth16.pk.bytes".into(),
-            verdict_positive_pk: "../scripts/docker/keys/verdict_positive.groth16.pk.bytes".into(),            
+            verdict_positive_pk: "../scripts/docker/keys/verdict_positive.groth16.pk.bytes".into(),
+            keystore_path: ".bright_disputes_keystore.json".into(),
+            accounts: BTreeMap::new(),
         }
     }
 }
@@ -1,22 +1,37 @@
 use std::fmt::{Display, Formatter};
 use std::path::Path;
 
-use aleph_client::{contract::ContractInstance, AccountId, SignedConnection};
+use aleph_client::{
+    contract::{
+        event::{listen_contract_events, ContractEvent},
+        ContractInstance,
+    },
+    AccountId, Connection, SignedConnection, SignedConnectionApi,
+};
 use anyhow::{anyhow, Result};
-use ark_ed_on_bls12_381::EdwardsProjective as JubJub;
 use ark_std::vec::Vec;
 use bright_disputes_lib::{
+    decrypt_evidence, encrypt_evidence, generate_ecdh_keys,
     helpers::{account_id_to_string, to_ink_account_id},
-    prepare_counting_inputs, prepare_voting_inputs, PublicVote,
+    make_vote_commitment, prepare_counting_inputs, prepare_voting_inputs, verdict_public_input,
+    verdict_votes_hash, verify_proof, vote_public_input, PublicVote,
 };
-use ink_wrapper_types::{Connection as _, SignedConnection as _};
-use liminal_ark_relations::disputes::{
-    ecdh::{Ecdh, EcdhScheme},
-    VerdictRelation,
+use futures::channel::mpsc::UnboundedSender;
+use ink_wrapper_types::{Connection as _, ExecCall, SignedConnection as _};
+use liminal_ark_relations::disputes::VerdictRelation;
+use tracing::{info, warn};
+
+use crate::{
+    bright_disputes_ink::{
+        ActionKind, Dispute, DisputeFilter, Instance, JurorStats, MajorityThreshold, Relation,
+        Verdict, VotingMode,
+    },
+    keys::verifying_key_path,
+    output::Transaction,
 };
-use tracing::info;
 
-use crate::bright_disputes_ink::{Dispute, Instance, Verdict};
+/// Vote of the juror who abstains, it matches the contract `Vote::ABSTAIN`.
+pub const ABSTAIN_VOTE: u8 = 2;
 
 impl From<&ContractInstance> for Instance {
     fn from(contract: &ContractInstance) -> Self {
@@ -62,6 +77,66 @@ impl BrightDisputes {
         })
     }
 
+    /// Sends the call and collects the events, which the contract emitted in the transaction.
+    async fn exec(&self, connection: &SignedConnection, call: ExecCall) -> Result<Transaction> {
+        let tx_info = connection.exec(call).await?;
+        let (block_hash, tx_hash) = (tx_info.block_hash, tx_info.tx_hash);
+
+        let ink_contract: Instance = (&self.contract).into();
+        let events = connection
+            .get_contract_events(tx_info)
+            .await?
+            .for_contract(ink_contract)
+            .into_iter()
+            .filter_map(|event| event.ok())
+            .map(|event| format!("{event:?}"))
+            .collect();
+
+        Ok(Transaction {
+            block_hash: format!("{block_hash:?}"),
+            tx_hash: format!("{tx_hash:?}"),
+            events,
+        })
+    }
+
+    /// Get actions, which caller needs to take in its disputes.
+    pub async fn pending_actions(
+        &self,
+        connection: &SignedConnection,
+    ) -> Result<Vec<(u32, ActionKind, Option<u64>)>> {
+        let ink_contract: Instance = (&self.contract).into();
+        let account_id = to_ink_account_id(connection.account_id());
+
+        let actions = connection
+            .read(ink_contract.pending_actions(account_id))
+            .await??;
+        Ok(actions)
+    }
+
+    /// Get actions, which account needs to take in its disputes. Unlike 'pending_actions',
+    /// it doesn't need the account key pair.
+    pub async fn account_pending_actions(
+        &self,
+        connection: &Connection,
+        account_id: &AccountId,
+    ) -> Result<Vec<(u32, ActionKind, Option<u64>)>> {
+        let ink_contract: Instance = (&self.contract).into();
+
+        let actions = connection
+            .read(ink_contract.pending_actions(to_ink_account_id(account_id)))
+            .await??;
+        Ok(actions)
+    }
+
+    /// Subscribes to the finalized blocks and sends events emitted by the contract.
+    pub async fn listen_events(
+        &self,
+        connection: &Connection,
+        sender: UnboundedSender<Result<ContractEvent>>,
+    ) -> Result<()> {
+        listen_contract_events(connection, &[&self.contract], sender).await
+    }
+
     /// Calls 'get_dispute' of the contract and returns dispute.
     pub async fn get_dispute(
         &self,
@@ -80,6 +155,61 @@ impl BrightDisputes {
         }
     }
 
+    /// Calls 'get_disputes' of the contract and returns the page of disputes, which meet the filter conditions.
+    pub async fn get_disputes(
+        &self,
+        connection: &SignedConnection,
+        offset: u32,
+        limit: u32,
+        filter: DisputeFilter,
+    ) -> Result<Vec<Dispute>> {
+        let ink_contract: Instance = (&self.contract).into();
+
+        let disputes = connection
+            .read(ink_contract.get_disputes(offset, limit, filter))
+            .await??;
+        Ok(disputes)
+    }
+
+    /// Calls 'get_juries_pool' of the contract and returns accounts of the active juries.
+    pub async fn get_juries_pool(
+        &self,
+        connection: &SignedConnection,
+    ) -> Result<Vec<ink_primitives::AccountId>> {
+        let ink_contract: Instance = (&self.contract).into();
+
+        let juries = connection.read(ink_contract.get_juries_pool()).await??;
+        Ok(juries)
+    }
+
+    /// Calls 'get_juror_stats' of the contract and returns reputation of the juror.
+    pub async fn get_juror_stats(
+        &self,
+        connection: &SignedConnection,
+        juror_id: ink_primitives::AccountId,
+    ) -> Result<JurorStats> {
+        let ink_contract: Instance = (&self.contract).into();
+
+        let stats = connection
+            .read(ink_contract.get_juror_stats(juror_id))
+            .await??;
+        Ok(stats)
+    }
+
+    /// Calls 'get_juror_suspension' of the contract and returns the time until juror is suspended.
+    pub async fn get_juror_suspension(
+        &self,
+        connection: &SignedConnection,
+        juror_id: ink_primitives::AccountId,
+    ) -> Result<Option<u64>> {
+        let ink_contract: Instance = (&self.contract).into();
+
+        let suspension = connection
+            .read(ink_contract.get_juror_suspension(juror_id))
+            .await??;
+        Ok(suspension)
+    }
+
     /// Calls 'juror_public_key' of the contract and returns dispute.
     pub async fn get_juror_public_key(
         &self,
@@ -99,20 +229,44 @@ impl BrightDisputes {
         }
     }
 
-    /// Calls 'create_dispute' of the contract. If success, return dispute id of newly created dispute.
+    /// Calls 'has_confirmed_participation' of the contract.
+    pub async fn has_confirmed_participation(
+        &self,
+        connection: &SignedConnection,
+        dispute_id: u32,
+        juror_id: ink_primitives::AccountId,
+    ) -> Result<bool> {
+        let ink_contract: Instance = (&self.contract).into();
+        connection
+            .read(ink_contract.has_confirmed_participation(dispute_id, juror_id))
+            .await??
+            .map_err(|e| anyhow!("Unable to check the participation: {e:?}"))
+    }
+
+    /// Calls 'create_dispute_with_voting_mode' of the contract. If success, return dispute id of newly created dispute
+    /// and the transaction.
     pub async fn create_dispute(
         &self,
         connection: &SignedConnection,
         owner_link: String,
         defendant_id: ink_primitives::AccountId,
         escrow: u128,
-    ) -> Result<u32> {
+        voting_mode: VotingMode,
+        majority_threshold: Option<MajorityThreshold>,
+    ) -> Result<(u32, Transaction)> {
         let ink_contract: Instance = (&self.contract).into();
 
-        connection
+        let transaction = self
             .exec(
+                connection,
                 ink_contract
-                    .create_dispute(owner_link, defendant_id, escrow)
+                    .create_dispute_with_voting_mode(
+                        owner_link,
+                        defendant_id,
+                        escrow,
+                        voting_mode,
+                        majority_threshold,
+                    )
                     .with_value(escrow),
             )
             .await?;
@@ -120,7 +274,7 @@ impl BrightDisputes {
         let res = connection
             .read(ink_contract.get_last_dispute_id())
             .await??;
-        Ok(res)
+        Ok((res, transaction))
     }
 
     /// Calls 'confirm_defendant' of the contract.
@@ -130,18 +284,16 @@ impl BrightDisputes {
         dispute_id: u32,
         defendant_link: String,
         escrow: u128,
-    ) -> Result<()> {
+    ) -> Result<Transaction> {
         let ink_contract: Instance = (&self.contract).into();
 
-        connection
-            .exec(
-                ink_contract
-                    .confirm_defendant(dispute_id, defendant_link)
-                    .with_value(escrow),
-            )
-            .await?;
-
-        Ok(())
+        self.exec(
+            connection,
+            ink_contract
+                .confirm_defendant(dispute_id, defendant_link)
+                .with_value(escrow),
+        )
+        .await
     }
 
     /// Calls 'update_owner_description' of the contract.
@@ -150,14 +302,14 @@ impl BrightDisputes {
         connection: &SignedConnection,
         dispute_id: u32,
         owner_link: String,
-    ) -> Result<()> {
+    ) -> Result<Transaction> {
         let ink_contract: Instance = (&self.contract).into();
 
-        connection
-            .exec(ink_contract.update_owner_description(dispute_id, owner_link))
-            .await?;
-
-        Ok(())
+        self.exec(
+            connection,
+            ink_contract.update_owner_description(dispute_id, owner_link),
+        )
+        .await
     }
 
     /// Calls 'update_defendant_description' of the contract.
@@ -166,17 +318,19 @@ impl BrightDisputes {
         connection: &SignedConnection,
         dispute_id: u32,
         defendant_link: String,
-    ) -> Result<()> {
+    ) -> Result<Transaction> {
         let ink_contract: Instance = (&self.contract).into();
 
-        connection
-            .exec(ink_contract.update_defendant_description(dispute_id, defendant_link))
-            .await?;
-
-        Ok(())
+        self.exec(
+            connection,
+            ink_contract.update_defendant_description(dispute_id, defendant_link),
+        )
+        .await
     }
 
-    /// Calls 'vote' of the contract.
+    /// Calls 'vote' of the contract, or 'change_vote' if the juror has already voted. Changed
+    /// vote is encrypted with a fresh key, so it doesn't show if the vote has changed. Returns
+    /// 'None' in the dry run, when the vote is not submitted.
     pub async fn vote(
         &self,
         connection: &SignedConnection,
@@ -184,7 +338,8 @@ impl BrightDisputes {
         private_key: Vec<u8>,
         vote: u8,
         vote_pk_file: &Path,
-    ) -> Result<()> {
+        dry_run: bool,
+    ) -> Result<Option<Transaction>> {
         let dispute = self.get_dispute(connection, dispute_id).await?;
         let judge_id = dispute
             .judge
@@ -193,102 +348,175 @@ impl BrightDisputes {
             .get_juror_public_key(connection, dispute_id, judge_id)
             .await?;
 
+        let juror_id = to_ink_account_id(connection.account_id());
+        let vote_key = if dispute.votes.iter().any(|vote| vote.juror == juror_id) {
+            Some(generate_ecdh_keys())
+        } else {
+            None
+        };
+        let vote_private_key = match &vote_key {
+            Some((_, vote_private_key)) => vote_private_key.clone(),
+            None => private_key,
+        };
+
         let (encrypted_vote, new_encrypted_all_votes, proof) = prepare_voting_inputs(
             vote,
             dispute.votes_hash,
             judge_pub_key,
-            private_key,
+            vote_private_key,
             vote_pk_file,
         )?;
 
         info!(target: "bright_disputes_cli", "Proof generated");
 
+        verify_proof(
+            &proof,
+            vote_public_input(encrypted_vote, dispute.votes_hash, new_encrypted_all_votes),
+            &verifying_key_path(vote_pk_file),
+        )?;
+        info!(target: "bright_disputes_cli", "Proof verified locally");
+        if dry_run {
+            return Ok(None);
+        }
+
         let ink_contract: Instance = (&self.contract).into();
-        connection
-            .exec(ink_contract.vote(dispute_id, encrypted_vote, new_encrypted_all_votes, proof))
-            .await?;
+        let call = match vote_key {
+            Some((vote_public_key, _)) => ink_contract.change_vote(
+                dispute_id,
+                vote_public_key,
+                encrypted_vote,
+                new_encrypted_all_votes,
+                proof,
+            ),
+            None => ink_contract.vote(dispute_id, encrypted_vote, new_encrypted_all_votes, proof),
+        };
+        let transaction = self.exec(connection, call).await?;
 
-        Ok(())
+        Ok(Some(transaction))
     }
 
-    /// Calls 'register_as_an_active_juror' of the contract.
-    pub async fn register_as_an_active_juror(&self, connection: &SignedConnection) -> Result<()> {
+    /// Calls 'abstain' of the contract (private voting mode). Abstain is public, so it
+    /// doesn't need the proof.
+    pub async fn abstain(
+        &self,
+        connection: &SignedConnection,
+        dispute_id: u32,
+    ) -> Result<Transaction> {
         let ink_contract: Instance = (&self.contract).into();
 
-        connection
-            .exec(ink_contract.register_as_an_active_juror())
-            .await?;
-
-        Ok(())
+        self.exec(connection, ink_contract.abstain(dispute_id))
+            .await
     }
 
-    /// Calls 'unregister_as_an_active_juror' of the contract.
-    pub async fn unregister_as_an_active_juror(&self, connection: &SignedConnection) -> Result<()> {
-        let ink_contract: Instance = (&self.contract).into();
+    /// Calls 'commit_vote' of the contract. Returns the salt, which is needed to reveal the vote, and the transaction.
+    pub async fn commit_vote(
+        &self,
+        connection: &SignedConnection,
+        dispute_id: u32,
+        vote: u8,
+    ) -> Result<([u8; 32], Transaction)> {
+        let salt: [u8; 32] = rand::random();
+        let juror_id = to_ink_account_id(connection.account_id());
+        let commitment = make_vote_commitment(&juror_id, vote, &salt);
 
-        connection
-            .exec(ink_contract.unregister_as_an_active_juror())
+        let ink_contract: Instance = (&self.contract).into();
+        let transaction = self
+            .exec(connection, ink_contract.commit_vote(dispute_id, commitment))
             .await?;
 
-        Ok(())
+        Ok((salt, transaction))
     }
 
-    /// Calls 'confirm_juror_participation_in_dispute' of the contract.
-    pub async fn confirm_juror_participation_in_dispute(
+    /// Calls 'reveal_vote' of the contract.
+    pub async fn reveal_vote(
         &self,
         connection: &SignedConnection,
         dispute_id: u32,
-        escrow: u128,
-    ) -> Result<()> {
+        vote: u8,
+        salt: [u8; 32],
+    ) -> Result<Transaction> {
         let ink_contract: Instance = (&self.contract).into();
 
-        let rng = &mut rand::thread_rng();
-        let (pub_alice, priv_alice) = Ecdh::<JubJub>::generate_keys(rng);
+        self.exec(connection, ink_contract.reveal_vote(dispute_id, vote, salt))
+            .await
+    }
 
-        let serialized_pub = Ecdh::<JubJub>::serialize_public_key(pub_alice);
-        let serialized_priv = Ecdh::<JubJub>::serialize_private_key(priv_alice);
+    /// Calls 'register_vk' of the contract.
+    pub async fn register_vk(
+        &self,
+        connection: &SignedConnection,
+        relation: Relation,
+        vk: Vec<u8>,
+    ) -> Result<Transaction> {
+        let ink_contract: Instance = (&self.contract).into();
 
-        info!(target: "bright_disputes_cli", "Public key: {:?} \n Private key: {:?}!", serialized_pub, serialized_priv);
+        self.exec(connection, ink_contract.register_vk(relation, vk))
+            .await
+    }
 
-        connection
-            .exec(
-                ink_contract
-                    .confirm_juror_participation_in_dispute(dispute_id, serialized_pub)
-                    .with_value(escrow),
-            )
-            .await?;
+    /// Calls 'register_as_an_active_juror' of the contract.
+    pub async fn register_as_an_active_juror(
+        &self,
+        connection: &SignedConnection,
+    ) -> Result<Transaction> {
+        let ink_contract: Instance = (&self.contract).into();
 
-        Ok(())
+        self.exec(connection, ink_contract.register_as_an_active_juror())
+            .await
     }
 
-    /// Calls 'confirm_judge_participation_in_dispute' of the contract.
-    pub async fn confirm_judge_participation_in_dispute(
+    /// Calls 'unregister_as_an_active_juror' of the contract.
+    pub async fn unregister_as_an_active_juror(
+        &self,
+        connection: &SignedConnection,
+    ) -> Result<Transaction> {
+        let ink_contract: Instance = (&self.contract).into();
+
+        self.exec(connection, ink_contract.unregister_as_an_active_juror())
+            .await
+    }
+
+    /// Calls 'confirm_juror_participation_in_dispute' of the contract. 'public_key' is
+    /// the ECDH public key of the juror in the dispute.
+    pub async fn confirm_juror_participation_in_dispute(
         &self,
         connection: &SignedConnection,
         dispute_id: u32,
         escrow: u128,
-    ) -> Result<()> {
+        public_key: Vec<u8>,
+    ) -> Result<Transaction> {
         let ink_contract: Instance = (&self.contract).into();
 
-        let rng = &mut rand::thread_rng();
-        let (pub_alice, priv_alice) = Ecdh::<JubJub>::generate_keys(rng);
-
-        let serialized_pub = Ecdh::<JubJub>::serialize_public_key(pub_alice);
-        let serialized_priv = Ecdh::<JubJub>::serialize_private_key(priv_alice);
+        self.exec(
+            connection,
+            ink_contract
+                .confirm_juror_participation_in_dispute(dispute_id, public_key)
+                .with_value(escrow),
+        )
+        .await
+    }
 
-        info!(target: "bright_disputes_cli", "Judge Public key: {:?} \n Private key: {:?}!", serialized_pub, serialized_priv);
-        connection
-            .exec(
-                ink_contract
-                    .confirm_judge_participation_in_dispute(dispute_id, serialized_pub)
-                    .with_value(escrow),
-            )
-            .await?;
+    /// Calls 'confirm_judge_participation_in_dispute' of the contract. 'public_key' is
+    /// the ECDH public key of the judge in the dispute.
+    pub async fn confirm_judge_participation_in_dispute(
+        &self,
+        connection: &SignedConnection,
+        dispute_id: u32,
+        escrow: u128,
+        public_key: Vec<u8>,
+    ) -> Result<Transaction> {
+        let ink_contract: Instance = (&self.contract).into();
 
-        Ok(())
+        self.exec(
+            connection,
+            ink_contract
+                .confirm_judge_participation_in_dispute(dispute_id, public_key)
+                .with_value(escrow),
+        )
+        .await
     }
 
-    /// Calls 'count_the_votes' of the contract.
+    /// Calls 'count_the_votes' of the contract. Returns 'None' in the dry run, when the verdict is not issued.
     pub async fn count_the_votes(
         &self,
         connection: &SignedConnection,
@@ -297,14 +525,20 @@ impl BrightDisputes {
         verdict_none_pk: &Path,
         verdict_negative_pk: &Path,
         verdict_positive_pk: &Path,
-    ) -> Result<()> {
+        dry_run: bool,
+    ) -> Result<Option<Transaction>> {
         let dispute = self.get_dispute(connection, dispute_id).await?;
 
+        // Changed votes are encrypted with their own keys.
         let mut jurors_public_key = Vec::<Vec<u8>>::new();
         for vote in &dispute.votes {
-            let key = self
-                .get_juror_public_key(connection, dispute_id, vote.juror)
-                .await?;
+            let key = match &vote.public_key {
+                Some(key) => key.clone(),
+                None => {
+                    self.get_juror_public_key(connection, dispute_id, vote.juror)
+                        .await?
+                }
+            };
             jurors_public_key.push(key);
         }
 
@@ -319,37 +553,87 @@ impl BrightDisputes {
             })
             .collect();
 
-        let (votes_maximum, votes_minimum, verdict, hashed_votes, jurors_banned, proof) =
-            prepare_counting_inputs(
-                judge_private_key,
-                votes,
-                verdict_none_pk,
-                verdict_negative_pk,
-                verdict_positive_pk,
-            )?;
-
-        let ink_verdict = match verdict {
-            VerdictRelation::Positive => Verdict::Positive(),
-            VerdictRelation::Negative => Verdict::Negative(),
-            VerdictRelation::None => Verdict::None(),
-        };
+        let ink_contract: Instance = (&self.contract).into();
+        let (votes_minimum, votes_maximum) = connection
+            .read(ink_contract.get_verdict_thresholds(dispute_id))
+            .await??
+            .map_err(|e| anyhow!("Unable to get verdict thresholds: {e:?}"))?;
+        let inputs = prepare_counting_inputs(
+            judge_private_key,
+            votes,
+            votes_minimum,
+            votes_maximum,
+            verdict_none_pk,
+            verdict_negative_pk,
+            verdict_positive_pk,
+        )?;
 
-        info!(target: "bright_disputes_cli", "Proof generated");
+        info!(target: "bright_disputes_cli", "Proofs generated");
 
-        let ink_contract: Instance = (&self.contract).into();
-        connection
-            .exec(ink_contract.issue_the_verdict(
-                dispute_id,
-                votes_maximum,
-                votes_minimum,
-                ink_verdict,
-                hashed_votes,
-                jurors_banned,
-                proof,
-            ))
+        // Majority proof is made for the votes of juries who are not banned, minority
+        // proof for the votes of banned ones.
+        let (minority_votes, majority_votes): (Vec<_>, Vec<_>) = dispute
+            .votes
+            .iter()
+            .partition(|vote| inputs.jurors_banned.contains(&vote.juror));
+        let majority_hash =
+            verdict_votes_hash(&majority_votes.iter().map(|v| v.vote).collect::<Vec<_>>());
+        let minority_hash =
+            verdict_votes_hash(&minority_votes.iter().map(|v| v.vote).collect::<Vec<_>>());
+        let negative_vk = verifying_key_path(verdict_negative_pk);
+        let positive_vk = verifying_key_path(verdict_positive_pk);
+        let ink_verdict = match inputs.verdict {
+            VerdictRelation::Positive => {
+                let votes_count = majority_votes.len() as u8;
+                let input =
+                    verdict_public_input(VerdictRelation::Positive, votes_count, 0, majority_hash);
+                verify_proof(&inputs.proof, input, &positive_vk)?;
+                let input = verdict_public_input(VerdictRelation::Negative, 0, 0, minority_hash);
+                verify_proof(&inputs.minority_proof, input, &negative_vk)?;
+                Verdict::Positive()
+            }
+            VerdictRelation::Negative => {
+                let input = verdict_public_input(VerdictRelation::Negative, 0, 0, majority_hash);
+                verify_proof(&inputs.proof, input, &negative_vk)?;
+                let votes_count = minority_votes.len() as u8;
+                let input =
+                    verdict_public_input(VerdictRelation::Positive, votes_count, 0, minority_hash);
+                verify_proof(&inputs.minority_proof, input, &positive_vk)?;
+                Verdict::Negative()
+            }
+            VerdictRelation::None => {
+                let input = verdict_public_input(
+                    VerdictRelation::None,
+                    votes_minimum,
+                    votes_maximum,
+                    inputs.hashed_votes,
+                );
+                verify_proof(&inputs.proof, input, &verifying_key_path(verdict_none_pk))?;
+                Verdict::None()
+            }
+        };
+        info!(target: "bright_disputes_cli", "Proofs verified locally");
+        if dry_run {
+            return Ok(None);
+        }
+
+        let transaction = self
+            .exec(
+                connection,
+                ink_contract.issue_the_verdict(
+                    dispute_id,
+                    votes_maximum,
+                    votes_minimum,
+                    ink_verdict,
+                    inputs.hashed_votes,
+                    inputs.proof,
+                    inputs.jurors_banned,
+                    inputs.minority_proof,
+                ),
+            )
             .await?;
 
-        Ok(())
+        Ok(Some(transaction))
     }
 
     /// Calls 'process_dispute_round' of the contract.
@@ -357,13 +641,128 @@ impl BrightDisputes {
         &self,
         connection: &SignedConnection,
         dispute_id: u32,
-    ) -> Result<()> {
+    ) -> Result<Transaction> {
         let ink_contract: Instance = (&self.contract).into();
 
-        connection
-            .exec(ink_contract.process_dispute_round(dispute_id))
+        self.exec(connection, ink_contract.process_dispute_round(dispute_id))
+            .await
+    }
+
+    pub async fn request_default_judgment(
+        &self,
+        connection: &SignedConnection,
+        dispute_id: u32,
+    ) -> Result<Transaction> {
+        let ink_contract: Instance = (&self.contract).into();
+
+        self.exec(
+            connection,
+            ink_contract.request_default_judgment(dispute_id),
+        )
+        .await
+    }
+
+    pub async fn propose_settlement(
+        &self,
+        connection: &SignedConnection,
+        dispute_id: u32,
+        owner_share: u8,
+    ) -> Result<Transaction> {
+        let ink_contract: Instance = (&self.contract).into();
+
+        self.exec(
+            connection,
+            ink_contract.propose_settlement(dispute_id, owner_share),
+        )
+        .await
+    }
+
+    pub async fn accept_settlement(
+        &self,
+        connection: &SignedConnection,
+        dispute_id: u32,
+    ) -> Result<Transaction> {
+        let ink_contract: Instance = (&self.contract).into();
+
+        self.exec(connection, ink_contract.accept_settlement(dispute_id))
+            .await
+    }
+
+    /// Encrypts the evidence for the judge and all juries, who confirmed their
+    /// participation in the dispute and calls 'submit_evidence' of the contract.
+    /// Accounts, which public key can't be fetched, are skipped with a warning.
+    /// Returns the number of accounts, evidence was encrypted for, and the transaction.
+    pub async fn submit_evidence(
+        &self,
+        connection: &SignedConnection,
+        dispute_id: u32,
+        evidence: Vec<u8>,
+    ) -> Result<(usize, Transaction)> {
+        let dispute = self.get_dispute(connection, dispute_id).await?;
+
+        let mut jurors = Vec::<ink_primitives::AccountId>::new();
+        let mut jurors_public_key = Vec::<Vec<u8>>::new();
+        for juror_id in dispute.judge.into_iter().chain(dispute.juries) {
+            match self
+                .get_juror_public_key(connection, dispute_id, juror_id)
+                .await
+            {
+                Ok(key) => {
+                    jurors.push(juror_id);
+                    jurors_public_key.push(key);
+                }
+                Err(e) => warn!(
+                    "Evidence is not encrypted for {}: {e}",
+                    account_id_to_string(&juror_id)
+                ),
+            }
+        }
+        if jurors.is_empty() {
+            return Err(anyhow!(
+                "Failed to submit evidence, no juror has confirmed participation in the dispute!"
+            ));
+        }
+
+        let (public_key, encrypted) = encrypt_evidence(&evidence, jurors_public_key)?;
+        let evidences: Vec<(ink_primitives::AccountId, Vec<u8>)> =
+            jurors.into_iter().zip(encrypted).collect();
+        let number_of_jurors = evidences.len();
+
+        let ink_contract: Instance = (&self.contract).into();
+        let transaction = self
+            .exec(
+                connection,
+                ink_contract.submit_evidence(dispute_id, public_key, evidences),
+            )
             .await?;
 
-        Ok(())
+        Ok((number_of_jurors, transaction))
+    }
+
+    /// Calls 'get_evidence' of the contract and decrypts the evidence attached
+    /// for the caller with his private key.
+    pub async fn get_evidence(
+        &self,
+        connection: &SignedConnection,
+        dispute_id: u32,
+        private_key: Vec<u8>,
+    ) -> Result<Vec<Vec<u8>>> {
+        let ink_contract: Instance = (&self.contract).into();
+        let juror_id = to_ink_account_id(connection.account_id());
+
+        let evidences = connection
+            .read(ink_contract.get_evidence(dispute_id, juror_id))
+            .await??;
+
+        evidences
+            .iter()
+            .map(|evidence| {
+                decrypt_evidence(
+                    evidence.public_key.clone(),
+                    private_key.clone(),
+                    &evidence.data,
+                )
+            })
+            .collect()
     }
 }
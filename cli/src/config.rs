@@ -1,6 +1,8 @@
-use std::path::PathBuf;
+use std::{path::PathBuf, time::Duration};
 
-use clap::{Parser, Subcommand};
+use clap::{ArgGroup, Parser, Subcommand, ValueEnum};
+
+use crate::bright_disputes_ink::MajorityThreshold;
 
 #[derive(Parser)]
 pub struct Config {
@@ -13,16 +15,68 @@ pub struct Config {
     #[clap(long)]
     pub contract_address: Option<String>,
 
+    /// Format of the command result
+    #[clap(long, value_enum, default_value_t = OutputFormat::Text, global = true)]
+    pub output: OutputFormat,
+
     #[clap(subcommand)]
     pub command: Command,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Structured result as JSON
+    Json,
+    /// Fields of the result, one per row
+    Table,
+    /// Log messages
+    Text,
+}
+
+/// State of the dispute, used to filter disputes
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum DisputeStateArg {
+    Created,
+    Running,
+    Ended,
+    Closed,
+}
+
 #[derive(Clone, Subcommand)]
 pub enum Command {
     /// Set node address
     SetNode { node_address: String },
     /// Set smart contract address
     SetContract { contract_address: String },
+    /// Add account alias, which can be used instead of the caller account seed
+    #[clap(group(
+        ArgGroup::new("source")
+            .required(true)
+            .args(["suri_env", "suri_file", "keystore"])
+    ))]
+    AddAccount {
+        alias: String,
+        /// Name of the environment variable with the account SURI
+        #[clap(long)]
+        suri_env: Option<String>,
+        /// File with the account SURI
+        #[clap(long)]
+        suri_file: Option<PathBuf>,
+        /// Polkadot-JS JSON keystore of the account
+        #[clap(long)]
+        keystore: Option<PathBuf>,
+    },
+    /// Remove account alias
+    RemoveAccount { alias: String },
+    /// Generate proving and verifying keys of the vote and verdict relations
+    ///
+    /// The setup randomness is a fixed seed of the relations library, so the toxic waste
+    /// is known to anybody and the proofs can be forged. Use the keys only on the
+    /// development chains.
+    Keygen {
+        #[clap(long, default_value = "../scripts/docker/keys")]
+        output_dir: PathBuf,
+    },
     /// Contract command
     #[clap(flatten)]
     Contract(ContractCmd),
@@ -36,6 +90,12 @@ pub enum ContractCmd {
         defendant_seed: String,
         owner_link: String,
         escrow: u128,
+        /// Juries commit and reveal their votes, instead of the private voting with zero-knowledge proofs
+        #[clap(long)]
+        commit_reveal: bool,
+        /// Part of the votes needed to reach the majority, e.g. "1/2", 70% of the votes if not given
+        #[clap(long, value_parser = parse_majority_threshold)]
+        majority_threshold: Option<MajorityThreshold>,
     },
     /// Confirms defendant
     ConfirmDefendant {
@@ -47,8 +107,12 @@ pub enum ContractCmd {
     CountTheVotes {
         caller_account: String,
         dispute_id: u32,
-        #[clap(value_parser, num_args = 1.., value_delimiter = ',')]
+        /// ECDH private key, taken from the keystore if not given
+        #[clap(value_parser, num_args = 0.., value_delimiter = ',')]
         private_key: Vec<u8>,
+        /// Verify the proof locally, without issuing the verdict
+        #[clap(long)]
+        dry_run: bool,
     },
     /// Get dispute
     GetDispute {
@@ -60,6 +124,43 @@ pub enum ContractCmd {
         caller_account: String,
         dispute_id: u32,
     },
+    /// List disputes, which meet the filter conditions
+    ListDisputes {
+        caller_account: String,
+        #[clap(long, value_enum)]
+        state: Option<DisputeStateArg>,
+        /// Owner of the dispute: account, alias or SS58 address
+        #[clap(long)]
+        owner: Option<String>,
+        /// Defendant of the dispute: account, alias or SS58 address
+        #[clap(long)]
+        defendant: Option<String>,
+        /// Judge or juror of the dispute: account, alias or SS58 address
+        #[clap(long)]
+        juror: Option<String>,
+        /// Number of matching disputes to skip
+        #[clap(long, default_value_t = 0)]
+        offset: u32,
+        /// Maximum number of disputes, capped at 50 by the contract
+        #[clap(long, default_value_t = 50)]
+        limit: u32,
+    },
+    /// Show the current round of the dispute, its deadline and which jurors confirmed or voted
+    DisputeTimeline {
+        caller_account: String,
+        dispute_id: u32,
+    },
+    /// List accounts registered in the active juries pool
+    JuriesPool { caller_account: String },
+    /// Show stats and suspension of the juror, and its public key in the dispute
+    JurorInfo {
+        caller_account: String,
+        /// Juror: account, alias or SS58 address
+        juror: String,
+        /// Dispute, which juror public key is shown for
+        #[clap(long)]
+        dispute_id: Option<u32>,
+    },
     /// Update owner description of the dispute
     UpdateOwnerDescription {
         caller_account: String,
@@ -72,13 +173,47 @@ pub enum ContractCmd {
         dispute_id: u32,
         defendant_link: String,
     },
-    /// Make a vote (call by juror)
+    /// Make a vote in the private voting mode (call by juror), the next call changes the vote
     Vote {
         caller_account: String,
         dispute_id: u32,
+        /// 1 - in favour of the owner, 0 - in favour of the defendant, 2 - abstain (public, without the proof)
+        #[clap(value_parser = clap::value_parser!(u8).range(0..=2))]
         vote: u8,
-        #[clap(value_parser, num_args = 1.., value_delimiter = ',')]
+        /// ECDH private key, taken from the keystore if not given
+        #[clap(value_parser, num_args = 0.., value_delimiter = ',')]
         private_key: Vec<u8>,
+        /// Verify the proof locally, without submitting the vote
+        #[clap(long)]
+        dry_run: bool,
+    },
+    /// Commit the vote in the commit-reveal voting mode (call by juror), the vote and its salt are stored in the keystore
+    CommitVote {
+        caller_account: String,
+        dispute_id: u32,
+        /// 1 - in favour of the owner, 0 - in favour of the defendant, 2 - abstain
+        #[clap(value_parser = clap::value_parser!(u8).range(0..=2))]
+        vote: u8,
+    },
+    /// Reveal the committed vote in the commit-reveal voting mode (call by juror)
+    RevealVote {
+        caller_account: String,
+        dispute_id: u32,
+        /// Committed vote, taken from the keystore with the salt if not given
+        #[clap(requires = "salt", value_parser = clap::value_parser!(u8).range(0..=2))]
+        vote: Option<u8>,
+        /// Salt of the committed vote, 32 bytes in hex
+        #[clap(long, requires = "vote", value_parser = parse_salt)]
+        salt: Option<[u8; 32]>,
+    },
+    /// Register verifying keys generated by the 'keygen' command in the contract
+    ///
+    /// Keys generated by 'keygen' come from the setup with the publicly known toxic
+    /// waste, register them only on the development chains.
+    RegisterVks {
+        caller_account: String,
+        #[clap(long, default_value = "../scripts/docker/keys")]
+        keys_dir: PathBuf,
     },
     /// Register as an active juror in bright disputes
     RegisterAsAnActiveJuror { caller_account: String },
@@ -99,4 +234,96 @@ pub enum ContractCmd {
         caller_account: String,
         dispute_id: u32,
     },
+    /// Request default judgment, when defendant ignores the dispute (call by owner)
+    RequestDefaultJudgment {
+        caller_account: String,
+        dispute_id: u32,
+    },
+    /// Propose the settlement of the dispute (call by owner or defendant)
+    ProposeSettlement {
+        caller_account: String,
+        dispute_id: u32,
+        /// Percentage of the parties funds, which goes to the owner
+        owner_share: u8,
+    },
+    /// Accept the settlement proposed by the other party of the dispute
+    AcceptSettlement {
+        caller_account: String,
+        dispute_id: u32,
+    },
+    /// List actions, which caller needs to take in its disputes
+    MyTasks { caller_account: String },
+    /// Attach evidence encrypted for the jurors of the dispute (call by owner or defendant)
+    SubmitEvidence {
+        caller_account: String,
+        dispute_id: u32,
+        evidence_path: PathBuf,
+    },
+    /// Decrypt evidence attached for the juror / judge of the dispute
+    ReadEvidence {
+        caller_account: String,
+        dispute_id: u32,
+        output_dir: PathBuf,
+        /// ECDH private key, taken from the keystore if not given
+        #[clap(value_parser, num_args = 0.., value_delimiter = ',')]
+        private_key: Vec<u8>,
+    },
+    /// Watch contract events and deadlines of the accounts, print notices or run hooks
+    Watch {
+        /// Accounts to watch: aliases, seeds or SS58 addresses, all account aliases if not given
+        accounts: Vec<String>,
+        /// Shell command run for each notice, which is passed in the BRIGHT_DISPUTES_* environment variables
+        #[clap(long)]
+        hook: Option<String>,
+        /// Local endpoint, which each notice is posted to as JSON
+        #[clap(long)]
+        webhook: Option<String>,
+        /// How long before the deadline the warning is given, e.g. "10m"
+        #[clap(long, default_value = "10m", value_parser = humantime::parse_duration)]
+        warn_before: Duration,
+        /// How often deadlines are checked
+        #[clap(long, default_value = "30s", value_parser = humantime::parse_duration)]
+        interval: Duration,
+    },
+    /// Act for the juror / judge: confirm participation, ask for votes, count the votes and process dispute rounds
+    Agent {
+        caller_account: String,
+        /// JSON file with the agent policy, all actions are allowed if not given
+        #[clap(long)]
+        policy: Option<PathBuf>,
+        /// How long before the deadline the warning is given, e.g. "10m"
+        #[clap(long, default_value = "10m", value_parser = humantime::parse_duration)]
+        warn_before: Duration,
+        /// How often pending actions are checked
+        #[clap(long, default_value = "30s", value_parser = humantime::parse_duration)]
+        interval: Duration,
+    },
+}
+
+/// Parse the majority threshold given as "numerator/denominator". The contract checks that
+/// `0 < numerator <= denominator` and rejects the dispute with `InvalidMajorityThreshold`.
+fn parse_majority_threshold(value: &str) -> Result<MajorityThreshold, String> {
+    let (numerator, denominator) = value
+        .split_once('/')
+        .ok_or("Expected \"numerator/denominator\"")?;
+    let numerator: u32 = numerator.trim().parse().map_err(|e| format!("{e}"))?;
+    let denominator: u32 = denominator.trim().parse().map_err(|e| format!("{e}"))?;
+    Ok(MajorityThreshold {
+        numerator,
+        denominator,
+    })
+}
+
+/// Parse the salt of the committed vote given as 32 bytes in hex, optionally prefixed with "0x".
+fn parse_salt(value: &str) -> Result<[u8; 32], String> {
+    let value = value.strip_prefix("0x").unwrap_or(value);
+    if value.len() != 64 || !value.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err("Salt has to be 32 bytes long, given in hex".into());
+    }
+    let mut salt = [0u8; 32];
+    for (byte, digits) in salt.iter_mut().zip(value.as_bytes().chunks(2)) {
+        let digits = std::str::from_utf8(digits).map_err(|e| format!("{e}"))?;
+        *byte = u8::from_str_radix(digits, 16).map_err(|e| format!("{e}"))?;
+    }
+    Ok(salt)
 }
@@ -0,0 +1,148 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use aleph_client::sp_core::hashing::blake2_256;
+use anyhow::{anyhow, Result};
+use bright_disputes_lib::{generate_verdict_keys, generate_vote_keys};
+use liminal_ark_relations::disputes::VerdictRelation;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::bright_disputes_ink::Relation;
+
+/// Name of the manifest file, stored next to the keys.
+pub const KEYS_MANIFEST: &str = "keys.manifest.json";
+
+/// Where the setup randomness of the keys comes from, stored in the manifest.
+pub const KEYS_SETUP: &str = "Single party Groth16 setup with the fixed seed of \
+    'liminal_ark_relations', the toxic waste is known to anybody who runs 'keygen' and \
+    allows to forge the proofs. Use the keys only on the development chains.";
+
+/// Keys of one relation, with blake2 hashes of their content.
+#[derive(Deserialize, Serialize)]
+pub struct RelationKeys {
+    pub relation: String,
+    pub pk: PathBuf,
+    pub pk_hash: String,
+    pub vk: PathBuf,
+    pub vk_hash: String,
+}
+
+/// Manifest of the keys generated by the 'keygen' command.
+#[derive(Deserialize, Serialize)]
+pub struct KeysManifest {
+    pub setup: String,
+    pub keys: Vec<RelationKeys>,
+}
+
+/// Relations used by the contract: name, file name prefix and contract relation.
+fn relations() -> [(&'static str, &'static str, Relation); 4] {
+    [
+        ("Vote", "vote", Relation::Vote()),
+        (
+            "VerdictNegative",
+            "verdict_negative",
+            Relation::VerdictNegative(),
+        ),
+        ("VerdictNone", "verdict_none", Relation::VerdictNone()),
+        (
+            "VerdictPositive",
+            "verdict_positive",
+            Relation::VerdictPositive(),
+        ),
+    ]
+}
+
+fn hash_to_hex(bytes: &[u8]) -> String {
+    blake2_256(bytes)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+/// Get path of the proving key of the relation stored in 'keys_dir'.
+pub fn proving_key_path(keys_dir: &Path, file_prefix: &str) -> PathBuf {
+    keys_dir.join(format!("{file_prefix}.groth16.pk.bytes"))
+}
+
+/// Get path of the verifying key, which matches the proving key. Both are
+/// generated in the same directory, with the '.pk.bytes' / '.vk.bytes' suffix.
+pub fn verifying_key_path(proving_key: &Path) -> PathBuf {
+    let file_name = proving_key
+        .file_name()
+        .map(|name| name.to_string_lossy().replace(".pk.bytes", ".vk.bytes"))
+        .unwrap_or_default();
+    proving_key.with_file_name(file_name)
+}
+
+/// Runs the Groth16 setup for the vote and verdict relations. Keys and the manifest
+/// are written to 'output_dir'. See 'KEYS_SETUP' for the setup randomness.
+pub fn generate_keys(output_dir: &Path) -> Result<KeysManifest> {
+    fs::create_dir_all(output_dir).map_err(|e| anyhow!("Failed to create {output_dir:?}: {e}"))?;
+
+    let mut keys = Vec::new();
+    for (name, file_prefix, relation) in relations() {
+        let (vk, pk) = match relation {
+            Relation::Vote() => generate_vote_keys(),
+            Relation::VerdictNegative() => generate_verdict_keys(VerdictRelation::Negative),
+            Relation::VerdictNone() => generate_verdict_keys(VerdictRelation::None),
+            Relation::VerdictPositive() => generate_verdict_keys(VerdictRelation::Positive),
+        };
+
+        let pk_path = proving_key_path(output_dir, file_prefix);
+        let vk_path = verifying_key_path(&pk_path);
+        fs::write(&pk_path, &pk).map_err(|e| anyhow!("Failed to save {pk_path:?}: {e}"))?;
+        fs::write(&vk_path, &vk).map_err(|e| anyhow!("Failed to save {vk_path:?}: {e}"))?;
+        info!(target: "bright_disputes_cli", "Generated keys for '{}' relation", name);
+
+        keys.push(RelationKeys {
+            relation: name.into(),
+            pk: pk_path.file_name().unwrap().into(),
+            pk_hash: hash_to_hex(&pk),
+            vk: vk_path.file_name().unwrap().into(),
+            vk_hash: hash_to_hex(&vk),
+        });
+    }
+
+    let manifest = KeysManifest {
+        setup: KEYS_SETUP.into(),
+        keys,
+    };
+    let content =
+        serde_json::to_string_pretty(&manifest).map_err(|e| anyhow!("Failed to serialize: {e}"))?;
+    fs::write(output_dir.join(KEYS_MANIFEST), content)
+        .map_err(|e| anyhow!("Failed to save keys manifest: {e}"))?;
+
+    Ok(manifest)
+}
+
+/// Load verifying keys listed in the manifest from 'keys_dir'. Content of each key
+/// has to match its hash from the manifest.
+pub fn load_verifying_keys(keys_dir: &Path) -> Result<Vec<(Relation, Vec<u8>)>> {
+    let manifest_path = keys_dir.join(KEYS_MANIFEST);
+    let content =
+        fs::read(&manifest_path).map_err(|e| anyhow!("Failed to load {manifest_path:?}: {e}"))?;
+    let manifest = serde_json::from_slice::<KeysManifest>(&content)
+        .map_err(|e| anyhow!("Failed to deserialize: {e}"))?;
+
+    let mut verifying_keys = Vec::new();
+    for (name, _, relation) in relations() {
+        let keys = manifest
+            .keys
+            .iter()
+            .find(|keys| keys.relation == name)
+            .ok_or_else(|| anyhow!("Missing '{name}' relation in the keys manifest!"))?;
+
+        let vk_path = keys_dir.join(&keys.vk);
+        let vk = fs::read(&vk_path).map_err(|e| anyhow!("Failed to load {vk_path:?}: {e}"))?;
+        if hash_to_hex(&vk) != keys.vk_hash {
+            return Err(anyhow!(
+                "Verifying key {vk_path:?} doesn't match the manifest!"
+            ));
+        }
+        verifying_keys.push((relation, vk));
+    }
+    Ok(verifying_keys)
+}
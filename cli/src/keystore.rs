@@ -0,0 +1,241 @@
+use std::{
+    env,
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use aleph_client::AccountId;
+use anyhow::{anyhow, Result};
+use bright_disputes_lib::{decrypt_with_passphrase, encrypt_with_passphrase};
+use inquire::Password;
+use serde::{Deserialize, Serialize};
+
+/// Environment variable with the keystore passphrase, used instead of the prompt.
+pub const KEYSTORE_PASSPHRASE_ENV: &str = "BRIGHT_DISPUTES_KEYSTORE_PASSPHRASE";
+
+/// Kind of the secret stored in the keystore.
+#[derive(Default, Deserialize, PartialEq, Serialize)]
+enum SecretKind {
+    /// ECDH private key of the juror / judge
+    #[default]
+    PrivateKey,
+    /// Committed vote and its salt (commit-reveal voting mode)
+    CommittedVote,
+}
+
+/// Secret of the account in the dispute, encrypted with the passphrase.
+#[derive(Deserialize, Serialize)]
+struct KeystoreEntry {
+    account: String,
+    dispute_id: u32,
+    #[serde(default)]
+    kind: SecretKind,
+    #[serde(alias = "private_key")]
+    secret: Vec<u8>,
+}
+
+/// Keystore of the juror / judge ECDH private keys and committed votes, stored on disk.
+pub struct Keystore {
+    path: PathBuf,
+    entries: Vec<KeystoreEntry>,
+    passphrase: Option<String>,
+}
+
+impl Keystore {
+    /// Load the keystore from 'path', it is empty if the file doesn't exist.
+    pub fn load(path: &Path) -> Result<Self> {
+        Ok(Self {
+            path: path.into(),
+            entries: read_entries(path)?,
+            passphrase: None,
+        })
+    }
+
+    /// Load the entries again, so the ones stored by other commands are seen. The
+    /// passphrase stays unlocked.
+    pub fn reload(&mut self) -> Result<()> {
+        self.entries = read_entries(&self.path)?;
+        Ok(())
+    }
+
+    /// Get the passphrase for storing the secrets ahead, so it is not asked after the
+    /// transaction. The passphrase has to decrypt the existing entries, it is used for
+    /// reading them too.
+    pub fn unlock(mut self) -> Result<Self> {
+        self.passphrase = Some(self.store_passphrase()?);
+        Ok(self)
+    }
+
+    /// Encrypt and store the private key of the account in the dispute.
+    pub fn store(
+        &mut self,
+        account: &AccountId,
+        dispute_id: u32,
+        private_key: &[u8],
+    ) -> Result<()> {
+        self.store_secret(account, dispute_id, SecretKind::PrivateKey, private_key)
+    }
+
+    /// Get the decrypted private key of the account in the dispute.
+    pub fn private_key(&self, account: &AccountId, dispute_id: u32) -> Result<Vec<u8>> {
+        self.secret(account, dispute_id, SecretKind::PrivateKey)
+            .ok_or_else(|| {
+                anyhow!("Private key of {account} in the dispute {dispute_id} not found!")
+            })?
+    }
+
+    /// Encrypt and store the committed vote of the account in the dispute with its salt.
+    pub fn store_vote(
+        &mut self,
+        account: &AccountId,
+        dispute_id: u32,
+        vote: u8,
+        salt: &[u8; 32],
+    ) -> Result<()> {
+        let secret = [&[vote][..], &salt[..]].concat();
+        self.store_secret(account, dispute_id, SecretKind::CommittedVote, &secret)
+    }
+
+    /// Check if the committed vote of the account in the dispute is stored.
+    pub fn has_committed_vote(&self, account: &AccountId, dispute_id: u32) -> bool {
+        let account = account.to_string();
+        self.entries.iter().any(|entry| {
+            entry.account == account
+                && entry.dispute_id == dispute_id
+                && entry.kind == SecretKind::CommittedVote
+        })
+    }
+
+    /// Get the decrypted committed vote of the account in the dispute and its salt.
+    pub fn committed_vote(&self, account: &AccountId, dispute_id: u32) -> Result<(u8, [u8; 32])> {
+        let secret = self
+            .secret(account, dispute_id, SecretKind::CommittedVote)
+            .ok_or_else(|| {
+                anyhow!("Committed vote of {account} in the dispute {dispute_id} not found!")
+            })??;
+        let (vote, salt) = secret
+            .split_first()
+            .ok_or_else(|| anyhow!("Invalid committed vote in the keystore!"))?;
+        let salt = salt
+            .try_into()
+            .map_err(|_| anyhow!("Invalid committed vote in the keystore!"))?;
+        Ok((*vote, salt))
+    }
+
+    fn store_secret(
+        &mut self,
+        account: &AccountId,
+        dispute_id: u32,
+        kind: SecretKind,
+        secret: &[u8],
+    ) -> Result<()> {
+        let passphrase = match &self.passphrase {
+            Some(passphrase) => passphrase.clone(),
+            None => self.store_passphrase()?,
+        };
+        let secret = encrypt_with_passphrase(secret, &passphrase)?;
+        let account = account.to_string();
+
+        self.entries.retain(|entry| {
+            entry.account != account || entry.dispute_id != dispute_id || entry.kind != kind
+        });
+        self.entries.push(KeystoreEntry {
+            account,
+            dispute_id,
+            kind,
+            secret,
+        });
+        self.save()
+    }
+
+    fn secret(
+        &self,
+        account: &AccountId,
+        dispute_id: u32,
+        kind: SecretKind,
+    ) -> Option<Result<Vec<u8>>> {
+        let account = account.to_string();
+        self.entries
+            .iter()
+            .find(|entry| {
+                entry.account == account && entry.dispute_id == dispute_id && entry.kind == kind
+            })
+            .map(|entry| {
+                let passphrase = match &self.passphrase {
+                    Some(passphrase) => passphrase.clone(),
+                    None => passphrase(false)?,
+                };
+                decrypt_with_passphrase(&entry.secret, &passphrase)
+            })
+    }
+
+    /// Get the passphrase for the new entry, it has to match the existing ones.
+    fn store_passphrase(&self) -> Result<String> {
+        let passphrase = passphrase(true)?;
+        if let Some(entry) = self.entries.first() {
+            decrypt_with_passphrase(&entry.secret, &passphrase).map_err(|_| {
+                anyhow!("Passphrase doesn't match the existing entries of the keystore!")
+            })?;
+        }
+        Ok(passphrase)
+    }
+
+    /// Save the entries to a temporary file next to the keystore, readable only by the
+    /// owner, and replace the keystore with it, so it is never left half-written.
+    fn save(&self) -> Result<()> {
+        let content = serde_json::to_string_pretty(&self.entries)
+            .map_err(|e| anyhow!("Failed to serialize keystore: {e}"))?;
+
+        let mut tmp_name = self.path.file_name().unwrap_or_default().to_os_string();
+        tmp_name.push(".tmp");
+        let tmp_path = self.path.with_file_name(tmp_name);
+
+        // Leftover of a failed save may have other permissions, the file is created anew.
+        let _ = fs::remove_file(&tmp_path);
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        options
+            .open(&tmp_path)
+            .and_then(|mut file| {
+                file.write_all(content.as_bytes())?;
+                file.sync_all()
+            })
+            .and_then(|_| fs::rename(&tmp_path, &self.path))
+            .map_err(|e| {
+                let _ = fs::remove_file(&tmp_path);
+                anyhow!("Failed to save keystore: {e}")
+            })
+    }
+}
+
+/// Read the keystore entries from 'path', there are none if the file doesn't exist.
+fn read_entries(path: &Path) -> Result<Vec<KeystoreEntry>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read(path).map_err(|e| anyhow!("Failed to load keystore: {e}"))?;
+    serde_json::from_slice::<Vec<KeystoreEntry>>(&content)
+        .map_err(|e| anyhow!("Failed to deserialize keystore: {e}"))
+}
+
+/// Get the keystore passphrase from the environment or prompt for it.
+fn passphrase(confirm: bool) -> Result<String> {
+    if let Ok(passphrase) = env::var(KEYSTORE_PASSPHRASE_ENV) {
+        return Ok(passphrase);
+    }
+    let prompt = Password::new("Keystore passphrase:");
+    let prompt = if confirm {
+        prompt
+    } else {
+        prompt.without_confirmation()
+    };
+    prompt
+        .prompt()
+        .map_err(|e| anyhow!("Failed to read passphrase: {e}"))
+}
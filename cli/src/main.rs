@@ -1,43 +1,61 @@
-use aleph_client::{
-    account_from_keypair, keypair_from_string, AccountId, Connection, SignedConnection,
-};
+use aleph_client::{AccountId, Connection, SignedConnection};
 use anyhow::{anyhow, Result};
-use bright_disputes_lib::helpers::to_ink_account_id;
+use bright_disputes_lib::{
+    generate_ecdh_keys,
+    helpers::{account_id_to_string, to_ink_account_id},
+};
 use clap::Parser;
+use futures::StreamExt;
 use inquire::Text;
 use std::str::FromStr;
-use std::{env, io};
+use std::{env, fs, io};
 use tracing::info;
 use tracing_subscriber::EnvFilter;
 
+mod accounts;
+mod agent;
 mod application;
 mod bright_disputes;
 mod bright_disputes_ink;
 mod config;
+mod keys;
+mod keystore;
+mod output;
+mod watch;
 
 use crate::{
+    accounts::AccountSource,
+    agent::{Agent, Policy},
     application::Application,
-    bright_disputes::BrightDisputes,
-    config::{Command::SetNode, ContractCmd},
+    bright_disputes::{BrightDisputes, ABSTAIN_VOTE},
+    bright_disputes_ink::{DisputeFilter, DisputeState, VotingMode},
+    config::{Command::SetNode, ContractCmd, DisputeStateArg, OutputFormat},
+    keystore::Keystore,
+    output::{CommandOutput, DisputeView},
+    watch::Hooks,
 };
 
 use crate::{
     config::{
         Command, Config,
         ContractCmd::{
-            ConfirmDefendant, ConfirmJudgeParticipation, ConfirmJurorParticipation, CountTheVotes,
-            CreateDispute, GetDispute, GetDisputeFull, ProcessDisputeRound,
-            RegisterAsAnActiveJuror, UnregisterAsAnActiveJuror, UpdateDefendantDescription,
-            UpdateOwnerDescription, Vote,
+            AcceptSettlement, Agent as AgentCmd, CommitVote, ConfirmDefendant,
+            ConfirmJudgeParticipation, ConfirmJurorParticipation, CountTheVotes, CreateDispute,
+            DisputeTimeline, GetDispute, GetDisputeFull, JuriesPool, JurorInfo, ListDisputes,
+            MyTasks, ProcessDisputeRound, ProposeSettlement, ReadEvidence, RegisterAsAnActiveJuror,
+            RegisterVks, RequestDefaultJudgment, RevealVote, SubmitEvidence,
+            UnregisterAsAnActiveJuror, UpdateDefendantDescription, UpdateOwnerDescription, Vote,
+            Watch,
         },
     },
-    Command::{Contract, SetContract},
+    Command::{AddAccount, Contract, Keygen, RemoveAccount, SetContract},
 };
 
 async fn handle_contract_command(
     app: &mut Application,
     cmd: ContractCmd,
-) -> Result<(), Box<dyn std::error::Error>> {
+    output_format: OutputFormat,
+) -> Result<CommandOutput, Box<dyn std::error::Error>> {
     let contract_address = match app.contract_address.clone() {
         Some(contract_address) => contract_address,
         None => {
@@ -56,42 +74,51 @@ async fn handle_contract_command(
 
     let bright_dispute = BrightDisputes::new(&contract_address, &app.metadata_path)?;
 
-    match cmd {
+    let output = match cmd {
         CreateDispute {
             caller_account,
             defendant_seed,
             owner_link,
             escrow,
+            commit_reveal,
+            majority_threshold,
         } => {
-            let account = keypair_from_string(&caller_account);
+            let account = accounts::keypair(app, &caller_account)?;
             let signed_connection = SignedConnection::from_connection(connection, account.clone());
 
-            let defendant_key = keypair_from_string(&defendant_seed);
-            let defendant_account = account_from_keypair(defendant_key.signer());
+            let defendant_account = accounts::account_id(app, &defendant_seed)?;
 
-            let dispute_id = bright_dispute
+            let (dispute_id, transaction) = bright_dispute
                 .create_dispute(
                     &signed_connection,
                     owner_link,
                     to_ink_account_id(&defendant_account),
                     escrow,
+                    if commit_reveal {
+                        VotingMode::CommitReveal()
+                    } else {
+                        VotingMode::Private()
+                    },
+                    majority_threshold,
                 )
                 .await?;
-            info!("New dispute created, id: {dispute_id}");
+            CommandOutput::new(format!("New dispute created, id: {dispute_id}"))
+                .with_dispute_id(dispute_id)
+                .with_transactions([transaction])
         }
         ConfirmDefendant {
             caller_account,
             dispute_id,
             defendant_link,
         } => {
-            let account = keypair_from_string(&caller_account);
+            let account = accounts::keypair(app, &caller_account)?;
             let signed_connection = SignedConnection::from_connection(connection, account.clone());
 
             let dispute = bright_dispute
                 .get_dispute(&signed_connection, dispute_id)
                 .await?;
 
-            bright_dispute
+            let transaction = bright_dispute
                 .confirm_defendant(
                     &signed_connection,
                     dispute_id,
@@ -99,20 +126,24 @@ async fn handle_contract_command(
                     dispute.escrow,
                 )
                 .await?;
-            info!(
-                "Defendant confirmed his participation in the dispute: {}!",
-                dispute_id
-            );
+            CommandOutput::new(format!(
+                "Defendant confirmed participation in the dispute: {dispute_id}!"
+            ))
+            .with_dispute_id(dispute_id)
+            .with_transactions([transaction])
         }
         CountTheVotes {
             caller_account,
             dispute_id,
             private_key,
+            dry_run,
         } => {
-            let account = keypair_from_string(&caller_account);
+            let account = accounts::keypair(app, &caller_account)?;
+            let private_key =
+                private_key_or_keystore(app, account.account_id(), dispute_id, private_key)?;
             let signed_connection = SignedConnection::from_connection(connection, account.clone());
 
-            bright_dispute
+            let transaction = bright_dispute
                 .count_the_votes(
                     &signed_connection,
                     dispute_id,
@@ -120,175 +151,759 @@ async fn handle_contract_command(
                     &app.verdict_none_pk,
                     &app.verdict_negative_pk,
                     &app.verdict_positive_pk,
+                    dry_run,
                 )
                 .await?;
-            info!("Votes counted: {}!", dispute_id);
+            let message = if dry_run {
+                format!("Dry run, verdict proof is valid: {dispute_id}!")
+            } else {
+                format!("Votes counted: {dispute_id}!")
+            };
+            CommandOutput::new(message)
+                .with_dispute_id(dispute_id)
+                .with_transactions(transaction)
         }
         GetDispute {
             caller_account,
             dispute_id,
         } => {
-            let account = keypair_from_string(&caller_account);
+            let account = accounts::keypair(app, &caller_account)?;
             let signed_connection = SignedConnection::from_connection(connection, account.clone());
 
             let dispute = bright_dispute
                 .get_dispute(&signed_connection, dispute_id)
                 .await?;
-            info!("{}", dispute);
+            CommandOutput::new(dispute.to_string()).with_dispute(&dispute)
         }
         GetDisputeFull {
             caller_account,
             dispute_id,
         } => {
-            let account = keypair_from_string(&caller_account);
+            let account = accounts::keypair(app, &caller_account)?;
             let signed_connection = SignedConnection::from_connection(connection, account.clone());
 
             let dispute = bright_dispute
                 .get_dispute(&signed_connection, dispute_id)
                 .await?;
-            info!(?dispute);
+            CommandOutput::new(format!("{dispute:?}")).with_dispute(&dispute)
+        }
+        ListDisputes {
+            caller_account,
+            state,
+            owner,
+            defendant,
+            juror,
+            offset,
+            limit,
+        } => {
+            let account = accounts::keypair(app, &caller_account)?;
+            let signed_connection = SignedConnection::from_connection(connection, account.clone());
+
+            let filter = DisputeFilter {
+                state: state.map(|state| match state {
+                    DisputeStateArg::Created => DisputeState::Created(),
+                    DisputeStateArg::Running => DisputeState::Running(),
+                    DisputeStateArg::Ended => DisputeState::Ended(),
+                    DisputeStateArg::Closed => DisputeState::Closed(),
+                }),
+                owner: optional_account_id(app, owner)?,
+                defendant: optional_account_id(app, defendant)?,
+                juror: optional_account_id(app, juror)?,
+            };
+            let disputes = bright_dispute
+                .get_disputes(&signed_connection, offset, limit, filter)
+                .await?;
+
+            let mut lines = Vec::new();
+            for dispute in &disputes {
+                let round = match &dispute.dispute_round {
+                    Some(round) => format!(
+                        ", round: {}, deadline: {}",
+                        output::enum_name(&round.state),
+                        output::timestamp_to_date(round.state_deadline)
+                    ),
+                    None => String::new(),
+                };
+                lines.push(format!(
+                    "Dispute {}: {}, owner: {}, defendant: {}{round}",
+                    dispute.id,
+                    output::enum_name(&dispute.state),
+                    account_id_to_string(&dispute.owner),
+                    account_id_to_string(&dispute.defendant)
+                ));
+            }
+            if lines.is_empty() {
+                lines.push("No disputes found!".into());
+            }
+            CommandOutput::new(lines.join("\n"))
+                .with_data(disputes.iter().map(DisputeView::from).collect::<Vec<_>>())?
+        }
+        DisputeTimeline {
+            caller_account,
+            dispute_id,
+        } => {
+            let account = accounts::keypair(app, &caller_account)?;
+            let signed_connection = SignedConnection::from_connection(connection, account.clone());
+
+            let dispute = bright_dispute
+                .get_dispute(&signed_connection, dispute_id)
+                .await?;
+
+            let mut lines = vec![format!(
+                "Dispute {}: {}, voting mode: {}",
+                dispute.id,
+                output::enum_name(&dispute.state),
+                output::enum_name(&dispute.voting_mode)
+            )];
+            match &dispute.dispute_round {
+                Some(round) => lines.push(format!(
+                    "Round {}: {}, deadline: {} ({})",
+                    dispute.dispute_round_counter,
+                    output::enum_name(&round.state),
+                    output::timestamp_to_date(round.state_deadline),
+                    output::time_to_deadline(round.state_deadline)
+                )),
+                None => lines.push("No dispute round is running".into()),
+            }
+
+            let mut participants = Vec::new();
+            let judge = dispute.judge.iter().map(|judge| ("Judge", *judge));
+            let juries = dispute.juries.iter().map(|juror| ("Juror", *juror));
+            for (role, account_id) in judge.chain(juries) {
+                let confirmed = bright_dispute
+                    .has_confirmed_participation(&signed_connection, dispute_id, account_id)
+                    .await?;
+                let voted = dispute.votes.iter().any(|vote| vote.juror == account_id);
+                let revealed = dispute
+                    .revealed_votes
+                    .iter()
+                    .any(|(juror, _)| *juror == account_id);
+
+                let mut progress = vec![if confirmed {
+                    "confirmed"
+                } else {
+                    "not confirmed"
+                }];
+                if role == "Juror" {
+                    // Abstain in the private voting mode is revealed without the vote.
+                    progress.push(match (voted, revealed) {
+                        (true, _) => "voted",
+                        (false, true) => "abstained",
+                        (false, false) => "not voted",
+                    });
+                    if voted && revealed {
+                        progress.push("revealed");
+                    }
+                }
+                lines.push(format!(
+                    "{role} {}: {}",
+                    account_id_to_string(&account_id),
+                    progress.join(", ")
+                ));
+                participants.push(serde_json::json!({
+                    "account": account_id_to_string(&account_id),
+                    "role": role,
+                    "confirmed": confirmed,
+                    "voted": voted,
+                    "revealed": revealed,
+                }));
+            }
+            for banned in &dispute.banned {
+                lines.push(format!("Banned {}", account_id_to_string(banned)));
+            }
+
+            CommandOutput::new(lines.join("\n"))
+                .with_dispute(&dispute)
+                .with_data(serde_json::json!({ "participants": participants }))?
+        }
+        JuriesPool { caller_account } => {
+            let account = accounts::keypair(app, &caller_account)?;
+            let signed_connection = SignedConnection::from_connection(connection, account.clone());
+
+            let juries: Vec<String> = bright_dispute
+                .get_juries_pool(&signed_connection)
+                .await?
+                .iter()
+                .map(account_id_to_string)
+                .collect();
+            let message = if juries.is_empty() {
+                "Juries pool is empty!".into()
+            } else {
+                format!("Juries pool ({}):\n{}", juries.len(), juries.join("\n"))
+            };
+            CommandOutput::new(message).with_data(juries)?
+        }
+        JurorInfo {
+            caller_account,
+            juror,
+            dispute_id,
+        } => {
+            let account = accounts::keypair(app, &caller_account)?;
+            let signed_connection = SignedConnection::from_connection(connection, account.clone());
+
+            let juror_id = to_ink_account_id(&accounts::account_id(app, &juror)?);
+            let in_pool = bright_dispute
+                .get_juries_pool(&signed_connection)
+                .await?
+                .contains(&juror_id);
+            let stats = bright_dispute
+                .get_juror_stats(&signed_connection, juror_id)
+                .await?;
+            let suspension = bright_dispute
+                .get_juror_suspension(&signed_connection, juror_id)
+                .await?;
+            let public_key = match dispute_id {
+                Some(dispute_id) => Some(
+                    bright_dispute
+                        .get_juror_public_key(&signed_connection, dispute_id, juror_id)
+                        .await?,
+                ),
+                None => None,
+            };
+
+            let mut lines = vec![
+                format!(
+                    "Juror {}: {}",
+                    account_id_to_string(&juror_id),
+                    if in_pool {
+                        "in the active juries pool"
+                    } else {
+                        "not in the active juries pool"
+                    }
+                ),
+                format!(
+                    "Cases served: {}, votes with majority: {}, bans: {}, missed deadlines: {}",
+                    stats.cases_served,
+                    stats.votes_with_majority,
+                    stats.bans,
+                    stats.missed_deadlines
+                ),
+            ];
+            match suspension {
+                Some(u64::MAX) => lines.push("Suspended permanently".into()),
+                Some(until) => lines.push(format!(
+                    "Suspended until: {}",
+                    output::timestamp_to_date(until)
+                )),
+                None => {}
+            }
+            if let (Some(dispute_id), Some(public_key)) = (dispute_id, &public_key) {
+                let public_key = public_key
+                    .iter()
+                    .map(|b| b.to_string())
+                    .collect::<Vec<_>>()
+                    .join(",");
+                lines.push(format!(
+                    "Public key in the dispute {dispute_id}: {public_key}"
+                ));
+            }
+
+            let result = CommandOutput::new(lines.join("\n")).with_data(serde_json::json!({
+                "account": account_id_to_string(&juror_id),
+                "in_pool": in_pool,
+                "cases_served": stats.cases_served,
+                "votes_with_majority": stats.votes_with_majority,
+                "bans": stats.bans,
+                "missed_deadlines": stats.missed_deadlines,
+                "suspended_until": suspension,
+                "public_key": public_key,
+            }))?;
+            match dispute_id {
+                Some(dispute_id) => result.with_dispute_id(dispute_id),
+                None => result,
+            }
         }
         UpdateOwnerDescription {
             caller_account,
             dispute_id,
             owner_link,
         } => {
-            let account = keypair_from_string(&caller_account);
+            let account = accounts::keypair(app, &caller_account)?;
             let signed_connection = SignedConnection::from_connection(connection, account.clone());
 
-            bright_dispute
+            let transaction = bright_dispute
                 .update_owner_description(&signed_connection, dispute_id, owner_link)
                 .await?;
-            info!("Updated Owner description link!");
+            CommandOutput::new("Updated Owner description link!")
+                .with_dispute_id(dispute_id)
+                .with_transactions([transaction])
         }
         UpdateDefendantDescription {
             caller_account,
             dispute_id,
             defendant_link,
         } => {
-            let account = keypair_from_string(&caller_account);
+            let account = accounts::keypair(app, &caller_account)?;
             let signed_connection = SignedConnection::from_connection(connection, account.clone());
 
-            bright_dispute
+            let transaction = bright_dispute
                 .update_defendant_description(&signed_connection, dispute_id, defendant_link)
                 .await?;
-            info!("Updated Defendant description link!");
+            CommandOutput::new("Updated Defendant description link!")
+                .with_dispute_id(dispute_id)
+                .with_transactions([transaction])
         }
         Vote {
             caller_account,
             dispute_id,
             vote,
             private_key,
+            dry_run,
         } => {
-            let account = keypair_from_string(&caller_account);
+            let account = accounts::keypair(app, &caller_account)?;
             let signed_connection = SignedConnection::from_connection(connection, account.clone());
 
-            bright_dispute
-                .vote(
-                    &signed_connection,
-                    dispute_id,
-                    private_key,
-                    vote,
-                    &app.vote_pk,
-                )
+            let transaction = if vote == ABSTAIN_VOTE {
+                // Abstain is public, it needs neither the private key nor the proof.
+                if dry_run {
+                    None
+                } else {
+                    Some(
+                        bright_dispute
+                            .abstain(&signed_connection, dispute_id)
+                            .await?,
+                    )
+                }
+            } else {
+                let private_key =
+                    private_key_or_keystore(app, account.account_id(), dispute_id, private_key)?;
+                bright_dispute
+                    .vote(
+                        &signed_connection,
+                        dispute_id,
+                        private_key,
+                        vote,
+                        &app.vote_pk,
+                        dry_run,
+                    )
+                    .await?
+            };
+            let message = if dry_run {
+                "Dry run, vote proof is valid!"
+            } else {
+                "Voting succeed!"
+            };
+            CommandOutput::new(message)
+                .with_dispute_id(dispute_id)
+                .with_transactions(transaction)
+        }
+        CommitVote {
+            caller_account,
+            dispute_id,
+            vote,
+        } => {
+            let account = accounts::keypair(app, &caller_account)?;
+            let signed_connection = SignedConnection::from_connection(connection, account.clone());
+
+            let mut keystore = Keystore::load(&app.keystore_path)?.unlock()?;
+            let (salt, transaction) = bright_dispute
+                .commit_vote(&signed_connection, dispute_id, vote)
+                .await?;
+            keystore.store_vote(account.account_id(), dispute_id, vote, &salt)?;
+            CommandOutput::new("Vote committed! The vote and its salt are stored in the keystore.")
+                .with_dispute_id(dispute_id)
+                .with_transactions([transaction])
+        }
+        RevealVote {
+            caller_account,
+            dispute_id,
+            vote,
+            salt,
+        } => {
+            let account = accounts::keypair(app, &caller_account)?;
+            let signed_connection = SignedConnection::from_connection(connection, account.clone());
+
+            let (vote, salt) = match vote.zip(salt) {
+                Some((vote, salt)) => (vote, salt),
+                None => Keystore::load(&app.keystore_path)?
+                    .committed_vote(account.account_id(), dispute_id)?,
+            };
+            let transaction = bright_dispute
+                .reveal_vote(&signed_connection, dispute_id, vote, salt)
                 .await?;
-            info!("Voting succeed!");
+            CommandOutput::new("Vote revealed!")
+                .with_dispute_id(dispute_id)
+                .with_transactions([transaction])
+        }
+        RegisterVks {
+            caller_account,
+            keys_dir,
+        } => {
+            let account = accounts::keypair(app, &caller_account)?;
+            let signed_connection = SignedConnection::from_connection(connection, account.clone());
+
+            let mut transactions = Vec::new();
+            for (relation, vk) in keys::load_verifying_keys(&keys_dir)? {
+                info!("Registering verifying key of {:?} relation", relation);
+                transactions.push(
+                    bright_dispute
+                        .register_vk(&signed_connection, relation, vk)
+                        .await?,
+                );
+            }
+            CommandOutput::new("Verifying keys registered!").with_transactions(transactions)
         }
         RegisterAsAnActiveJuror { caller_account } => {
-            let account = keypair_from_string(&caller_account);
+            let account = accounts::keypair(app, &caller_account)?;
             let signed_connection = SignedConnection::from_connection(connection, account.clone());
 
-            bright_dispute
+            let transaction = bright_dispute
                 .register_as_an_active_juror(&signed_connection)
                 .await?;
-            info!(
+            CommandOutput::new(format!(
                 "\"{}\" [{}] has registered for the Active Jurors Pool!",
                 caller_account,
                 account.account_id()
-            );
+            ))
+            .with_transactions([transaction])
         }
         UnregisterAsAnActiveJuror { caller_account } => {
-            let account = keypair_from_string(&caller_account);
+            let account = accounts::keypair(app, &caller_account)?;
             let signed_connection = SignedConnection::from_connection(connection, account.clone());
 
-            bright_dispute
+            let transaction = bright_dispute
                 .unregister_as_an_active_juror(&signed_connection)
                 .await?;
-            info!(
-                "\"{}\" has unregistered from the Active Jurors Pool!",
-                caller_account
-            );
+            CommandOutput::new(format!(
+                "\"{caller_account}\" has unregistered from the Active Jurors Pool!"
+            ))
+            .with_transactions([transaction])
         }
         ConfirmJurorParticipation {
             caller_account,
             dispute_id,
         } => {
-            let account = keypair_from_string(&caller_account);
+            let account = accounts::keypair(app, &caller_account)?;
             let signed_connection = SignedConnection::from_connection(connection, account.clone());
 
             let dispute = bright_dispute
                 .get_dispute(&signed_connection, dispute_id)
                 .await?;
-            bright_dispute
+            let (public_key, private_key) = generate_ecdh_keys();
+            let mut keystore = Keystore::load(&app.keystore_path)?.unlock()?;
+            let transaction = bright_dispute
                 .confirm_juror_participation_in_dispute(
                     &signed_connection,
                     dispute_id,
                     dispute.escrow,
+                    public_key,
                 )
                 .await?;
-            info!(
-                "Juror \"{}\", has confirmed his participation in the dispute: {}!",
-                caller_account, dispute_id
-            );
+            keystore.store(account.account_id(), dispute_id, &private_key)?;
+            CommandOutput::new(format!(
+                "Juror \"{caller_account}\", has confirmed participation in the dispute: {dispute_id}!"
+            ))
+            .with_dispute_id(dispute_id)
+            .with_transactions([transaction])
         }
         ConfirmJudgeParticipation {
             caller_account,
             dispute_id,
         } => {
-            let account = keypair_from_string(&caller_account);
+            let account = accounts::keypair(app, &caller_account)?;
             let signed_connection = SignedConnection::from_connection(connection, account.clone());
 
             let dispute = bright_dispute
                 .get_dispute(&signed_connection, dispute_id)
                 .await?;
 
-            bright_dispute
+            let (public_key, private_key) = generate_ecdh_keys();
+            let mut keystore = Keystore::load(&app.keystore_path)?.unlock()?;
+            let transaction = bright_dispute
                 .confirm_judge_participation_in_dispute(
                     &signed_connection,
                     dispute_id,
                     dispute.escrow,
+                    public_key,
                 )
                 .await?;
-            info!(
-                "Judge \"{}\", has confirmed his participation in the dispute: {}!",
-                caller_account, dispute_id
-            );
+            keystore.store(account.account_id(), dispute_id, &private_key)?;
+            CommandOutput::new(format!(
+                "Judge \"{caller_account}\", has confirmed participation in the dispute: {dispute_id}!"
+            ))
+            .with_dispute_id(dispute_id)
+            .with_transactions([transaction])
         }
         ProcessDisputeRound {
             caller_account,
             dispute_id,
         } => {
-            let account = keypair_from_string(&caller_account);
+            let account = accounts::keypair(app, &caller_account)?;
             let signed_connection = SignedConnection::from_connection(connection, account.clone());
 
-            bright_dispute
+            let transaction = bright_dispute
                 .process_dispute_round(&signed_connection, dispute_id)
                 .await?;
 
             let dispute = bright_dispute
                 .get_dispute(&signed_connection, dispute_id)
                 .await?;
-            let dispute_state = dispute.dispute_round.unwrap().state;
+            let message = match &dispute.dispute_round {
+                Some(round) => format!(
+                    "Successfully switched to the next dispute state: {:?}",
+                    round.state
+                ),
+                None => format!("Dispute round finished, dispute state: {:?}", dispute.state),
+            };
+            CommandOutput::new(message)
+                .with_dispute(&dispute)
+                .with_transactions([transaction])
+        }
+        RequestDefaultJudgment {
+            caller_account,
+            dispute_id,
+        } => {
+            let account = accounts::keypair(app, &caller_account)?;
+            let signed_connection = SignedConnection::from_connection(connection, account.clone());
+
+            let transaction = bright_dispute
+                .request_default_judgment(&signed_connection, dispute_id)
+                .await?;
+            CommandOutput::new(format!(
+                "Default judgment issued for the dispute: {dispute_id}!"
+            ))
+            .with_dispute_id(dispute_id)
+            .with_transactions([transaction])
+        }
+        ProposeSettlement {
+            caller_account,
+            dispute_id,
+            owner_share,
+        } => {
+            let account = accounts::keypair(app, &caller_account)?;
+            let signed_connection = SignedConnection::from_connection(connection, account.clone());
+
+            let transaction = bright_dispute
+                .propose_settlement(&signed_connection, dispute_id, owner_share)
+                .await?;
+            CommandOutput::new(format!(
+                "Settlement of the dispute {dispute_id} proposed, owner share: {owner_share}%"
+            ))
+            .with_dispute_id(dispute_id)
+            .with_transactions([transaction])
+        }
+        AcceptSettlement {
+            caller_account,
+            dispute_id,
+        } => {
+            let account = accounts::keypair(app, &caller_account)?;
+            let signed_connection = SignedConnection::from_connection(connection, account.clone());
+
+            let transaction = bright_dispute
+                .accept_settlement(&signed_connection, dispute_id)
+                .await?;
+            CommandOutput::new(format!("Dispute {dispute_id} settled!"))
+                .with_dispute_id(dispute_id)
+                .with_transactions([transaction])
+        }
+        MyTasks { caller_account } => {
+            let account = accounts::keypair(app, &caller_account)?;
+            let signed_connection = SignedConnection::from_connection(connection, account.clone());
+
+            let actions = bright_dispute.pending_actions(&signed_connection).await?;
+            let mut lines = Vec::new();
+            let mut tasks = Vec::new();
+            for (dispute_id, action, deadline) in actions {
+                match deadline {
+                    Some(deadline) => lines.push(format!(
+                        "Dispute {dispute_id}: {action:?}, deadline: {}",
+                        output::timestamp_to_date(deadline)
+                    )),
+                    None => lines.push(format!("Dispute {dispute_id}: {action:?}")),
+                }
+                tasks.push(serde_json::json!({
+                    "dispute_id": dispute_id,
+                    "action": output::enum_name(&action),
+                    "deadline": deadline,
+                    "deadline_date": deadline.map(output::timestamp_to_date),
+                }));
+            }
+            if lines.is_empty() {
+                lines.push(format!("No pending tasks for \"{caller_account}\"!"));
+            }
+            CommandOutput::new(lines.join("\n")).with_data(tasks)?
+        }
+        SubmitEvidence {
+            caller_account,
+            dispute_id,
+            evidence_path,
+        } => {
+            let account = accounts::keypair(app, &caller_account)?;
+            let signed_connection = SignedConnection::from_connection(connection, account.clone());
+
+            let evidence = fs::read(&evidence_path)?;
+            let (number_of_jurors, transaction) = bright_dispute
+                .submit_evidence(&signed_connection, dispute_id, evidence)
+                .await?;
+            CommandOutput::new(format!(
+                "Evidence {evidence_path:?} submitted for {number_of_jurors} juries of the dispute: {dispute_id}!"
+            ))
+            .with_dispute_id(dispute_id)
+            .with_data(serde_json::json!({ "number_of_jurors": number_of_jurors }))?
+            .with_transactions([transaction])
+        }
+        ReadEvidence {
+            caller_account,
+            dispute_id,
+            output_dir,
+            private_key,
+        } => {
+            let account = accounts::keypair(app, &caller_account)?;
+            let private_key =
+                private_key_or_keystore(app, account.account_id(), dispute_id, private_key)?;
+            let signed_connection = SignedConnection::from_connection(connection, account.clone());
+
+            let evidences = bright_dispute
+                .get_evidence(&signed_connection, dispute_id, private_key)
+                .await?;
+
+            fs::create_dir_all(&output_dir)?;
+            let mut paths = Vec::new();
+            for (index, evidence) in evidences.iter().enumerate() {
+                let path = output_dir.join(format!("dispute_{dispute_id}_evidence_{index}"));
+                fs::write(&path, evidence)?;
+                paths.push(path);
+            }
+            let message = if paths.is_empty() {
+                format!("No evidence in the dispute: {dispute_id}!")
+            } else {
+                paths
+                    .iter()
+                    .map(|path| format!("Evidence saved to: {path:?}"))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            };
+            CommandOutput::new(message)
+                .with_dispute_id(dispute_id)
+                .with_data(paths)?
+        }
+        Watch {
+            accounts,
+            hook,
+            webhook,
+            warn_before,
+            interval,
+        } => {
+            let accounts = watched_accounts(app, accounts)?;
+            let hooks = Hooks {
+                command: hook,
+                webhook,
+            };
             info!(
-                "Successfully switched to the next dispute state: {:?}",
-                dispute_state
+                "Watching {} accounts: {}",
+                accounts.len(),
+                accounts
+                    .iter()
+                    .map(|account| account.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
             );
+
+            let (sender, mut notices) = futures::channel::mpsc::unbounded();
+            let print_notices = async {
+                while let Some(notice) = notices.next().await {
+                    notice.print(output_format)?;
+                    hooks.run(&notice).await;
+                }
+                Ok::<(), anyhow::Error>(())
+            };
+            tokio::try_join!(
+                watch::watch_notices(
+                    &bright_dispute,
+                    &connection,
+                    &accounts,
+                    warn_before,
+                    interval,
+                    sender,
+                ),
+                print_notices
+            )?;
+            CommandOutput::new("Watching finished!")
         }
+        AgentCmd {
+            caller_account,
+            policy,
+            warn_before,
+            interval,
+        } => {
+            let account = accounts::keypair(app, &caller_account)?;
+            let policy = Policy::load(policy.as_deref())?;
+            let signed_connection =
+                SignedConnection::from_connection(connection.clone(), account.clone());
+            info!(
+                "Agent of \"{}\" [{}] started!",
+                caller_account,
+                account.account_id()
+            );
+
+            let (sender, notices) = futures::channel::mpsc::unbounded();
+            let accounts = [account.account_id().clone()];
+            let mut agent = Agent::new(
+                app,
+                &bright_dispute,
+                signed_connection,
+                policy,
+                output_format,
+            )?;
+            tokio::try_join!(
+                watch::watch_notices(
+                    &bright_dispute,
+                    &connection,
+                    &accounts,
+                    warn_before,
+                    interval,
+                    sender,
+                ),
+                agent.run(notices, interval)
+            )?;
+            CommandOutput::new("Agent stopped!")
+        }
+    };
+    Ok(output)
+}
+
+/// Resolve the accounts to watch, all account aliases if none is given.
+fn watched_accounts(app: &Application, accounts: Vec<String>) -> Result<Vec<AccountId>> {
+    let accounts = if accounts.is_empty() {
+        app.accounts.keys().cloned().collect()
+    } else {
+        accounts
+    };
+    if accounts.is_empty() {
+        return Err(anyhow!("No accounts to watch, add account alias first!"));
     }
-    Ok(())
+    accounts
+        .iter()
+        .map(|account| accounts::account_id(app, account))
+        .collect()
+}
+
+/// Resolve the optional account, given as an account, alias or SS58 address.
+fn optional_account_id(
+    app: &Application,
+    account: Option<String>,
+) -> Result<Option<ink_primitives::AccountId>> {
+    account
+        .map(|account| accounts::account_id(app, &account).map(|id| to_ink_account_id(&id)))
+        .transpose()
+}
+
+/// Get the private key given in the command or the one stored in the keystore.
+fn private_key_or_keystore(
+    app: &Application,
+    account: &AccountId,
+    dispute_id: u32,
+    private_key: Vec<u8>,
+) -> Result<Vec<u8>> {
+    if !private_key.is_empty() {
+        return Ok(private_key);
+    }
+    Keystore::load(&app.keystore_path)?.private_key(account, dispute_id)
 }
 
-fn setup_logging() -> Result<()> {
+/// Logs go to stdout in the text output format, otherwise to stderr, so that stdout
+/// contains only the command result.
+fn setup_logging(output: OutputFormat) -> Result<()> {
     let filter = EnvFilter::new(
         env::var("RUST_LOG")
             .as_deref()
@@ -296,33 +911,62 @@ fn setup_logging() -> Result<()> {
     );
 
     let subscriber = tracing_subscriber::fmt()
-        .with_writer(io::stdout)
         .with_target(false)
         .with_env_filter(filter);
-    subscriber.try_init().map_err(|err| anyhow!(err))
+    match output {
+        OutputFormat::Text => subscriber.with_writer(io::stdout).try_init(),
+        _ => subscriber.with_writer(io::stderr).try_init(),
+    }
+    .map_err(|err| anyhow!(err))
 }
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    setup_logging()?;
-
     let config = Config::parse();
+    setup_logging(config.output)?;
+
     let mut app = Application::load_or_create(&config.config_path)?;
 
-    match config.command.clone() {
+    let output = match config.command.clone() {
         SetNode { node_address } => {
             app.node_address = node_address.clone();
-            info!("Node address set to: {:?}", node_address);
+            CommandOutput::new(format!("Node address set to: {node_address:?}"))
         }
         SetContract { contract_address } => {
             app.contract_address = Some(contract_address.clone());
-            info!("Contract address set to: {:?}", contract_address);
+            CommandOutput::new(format!("Contract address set to: {contract_address:?}"))
+        }
+        AddAccount {
+            alias,
+            suri_env,
+            suri_file,
+            keystore,
+        } => {
+            let source = match (suri_env, suri_file, keystore) {
+                (Some(name), _, _) => AccountSource::SuriEnv(name),
+                (_, Some(path), _) => AccountSource::SuriFile(path),
+                (_, _, Some(path)) => AccountSource::Keystore(path),
+                _ => return Err("Account source is not set!".into()),
+            };
+            app.accounts.insert(alias.clone(), source);
+            CommandOutput::new(format!("Account alias added: {alias:?}"))
         }
-        Contract(cmd) => {
-            handle_contract_command(&mut app, cmd).await?;
+        RemoveAccount { alias } => {
+            app.accounts.remove(&alias);
+            CommandOutput::new(format!("Account alias removed: {alias:?}"))
         }
-    }
+        Keygen { output_dir } => {
+            let manifest = keys::generate_keys(&output_dir)?;
+            app.vote_pk = keys::proving_key_path(&output_dir, "vote");
+            app.verdict_none_pk = keys::proving_key_path(&output_dir, "verdict_none");
+            app.verdict_negative_pk = keys::proving_key_path(&output_dir, "verdict_negative");
+            app.verdict_positive_pk = keys::proving_key_path(&output_dir, "verdict_positive");
+            CommandOutput::new(format!("Keys generated in: {output_dir:?}")).with_data(manifest)?
+        }
+        Contract(cmd) => handle_contract_command(&mut app, cmd, config.output).await?,
+    };
 
     Application::save(&config.config_path, &app)?;
+    output.print(config.output)?;
     Ok(())
 }
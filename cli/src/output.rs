@@ -0,0 +1,256 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Result};
+use bright_disputes_lib::helpers::account_id_to_string;
+use serde::Serialize;
+use serde_json::Value;
+use tracing::info;
+
+use crate::{
+    bright_disputes_ink::{Dispute, DisputeRound},
+    config::OutputFormat,
+};
+
+/// Last timestamp, which can be formatted as the RFC 3339 date (9999-12-31T23:59:59Z).
+const MAX_DATE_TIMESTAMP: u64 = 253_402_300_799_000;
+
+/// Transaction sent by the command, with the events emitted by the contract.
+#[derive(Serialize)]
+pub struct Transaction {
+    pub block_hash: String,
+    pub tx_hash: String,
+    pub events: Vec<String>,
+}
+
+/// Round of the dispute, with the deadline as a date.
+#[derive(Serialize)]
+pub struct DisputeRoundView {
+    pub state: String,
+    pub number_of_juries: u8,
+    pub state_deadline: u64,
+    pub state_deadline_date: String,
+}
+
+impl From<&DisputeRound> for DisputeRoundView {
+    fn from(round: &DisputeRound) -> Self {
+        Self {
+            state: enum_name(&round.state),
+            number_of_juries: round.number_of_juries,
+            state_deadline: round.state_deadline,
+            state_deadline_date: timestamp_to_date(round.state_deadline),
+        }
+    }
+}
+
+/// Dispute with accounts in the SS58 format.
+#[derive(Serialize)]
+pub struct DisputeView {
+    pub id: u32,
+    pub state: String,
+    pub owner: String,
+    pub owner_link: String,
+    pub escrow: u128,
+    pub deposit: u128,
+    pub created_at: u64,
+    pub defendant: String,
+    pub defendant_link: Option<String>,
+    pub dispute_result: Option<String>,
+    pub dispute_round: Option<DisputeRoundView>,
+    pub dispute_round_counter: u8,
+    pub judge: Option<String>,
+    pub juries: Vec<String>,
+    pub banned: Vec<String>,
+    pub voted: Vec<String>,
+    pub settlement: Option<String>,
+    pub voting_mode: String,
+    pub revealed_votes: Vec<(String, u8)>,
+    pub majority_threshold: String,
+}
+
+impl From<&Dispute> for DisputeView {
+    fn from(dispute: &Dispute) -> Self {
+        Self {
+            id: dispute.id,
+            state: enum_name(&dispute.state),
+            owner: account_id_to_string(&dispute.owner),
+            owner_link: dispute.owner_link.clone(),
+            escrow: dispute.escrow,
+            deposit: dispute.deposit,
+            created_at: dispute.created_at,
+            defendant: account_id_to_string(&dispute.defendant),
+            defendant_link: dispute.defendant_link.clone(),
+            dispute_result: dispute.dispute_result.as_ref().map(enum_name),
+            dispute_round: dispute.dispute_round.as_ref().map(Into::into),
+            dispute_round_counter: dispute.dispute_round_counter,
+            judge: dispute.judge.as_ref().map(account_id_to_string),
+            juries: dispute.juries.iter().map(account_id_to_string).collect(),
+            banned: dispute.banned.iter().map(account_id_to_string).collect(),
+            voted: dispute
+                .votes
+                .iter()
+                .map(|vote| account_id_to_string(&vote.juror))
+                .collect(),
+            settlement: dispute.settlement.as_ref().map(|settlement| {
+                format!(
+                    "owner share {}%, proposed by {}",
+                    settlement.owner_share,
+                    account_id_to_string(&settlement.proposed_by)
+                )
+            }),
+            voting_mode: enum_name(&dispute.voting_mode),
+            revealed_votes: dispute
+                .revealed_votes
+                .iter()
+                .map(|(juror, vote)| (account_id_to_string(juror), *vote))
+                .collect(),
+            majority_threshold: format!(
+                "{}/{}",
+                dispute.majority_threshold.numerator, dispute.majority_threshold.denominator
+            ),
+        }
+    }
+}
+
+/// Result of the command, printed in the format selected with '--output'.
+#[derive(Default, Serialize)]
+pub struct CommandOutput {
+    /// Summary of the command, printed in the text format
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dispute_id: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dispute: Option<DisputeView>,
+    /// Command specific result
+    #[serde(skip_serializing_if = "Value::is_null")]
+    pub data: Value,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub transactions: Vec<Transaction>,
+}
+
+impl CommandOutput {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_dispute_id(mut self, dispute_id: u32) -> Self {
+        self.dispute_id = Some(dispute_id);
+        self
+    }
+
+    pub fn with_dispute(mut self, dispute: &Dispute) -> Self {
+        self.dispute_id = Some(dispute.id);
+        self.dispute = Some(dispute.into());
+        self
+    }
+
+    pub fn with_data(mut self, data: impl Serialize) -> Result<Self> {
+        self.data = serde_json::to_value(data).map_err(|e| anyhow!("Failed to serialize: {e}"))?;
+        Ok(self)
+    }
+
+    pub fn with_transactions(
+        mut self,
+        transactions: impl IntoIterator<Item = Transaction>,
+    ) -> Self {
+        self.transactions.extend(transactions);
+        self
+    }
+
+    /// Print the result. Text format logs the message and the transactions, table
+    /// format prints one row per field and json format prints the whole result.
+    pub fn print(&self, format: OutputFormat) -> Result<()> {
+        match format {
+            OutputFormat::Text => {
+                info!("{}", self.message);
+                for transaction in &self.transactions {
+                    info!(
+                        "Transaction {} included in the block {}",
+                        transaction.tx_hash, transaction.block_hash
+                    );
+                    for event in &transaction.events {
+                        info!("Event: {}", event);
+                    }
+                }
+            }
+            OutputFormat::Table => {
+                let value =
+                    serde_json::to_value(self).map_err(|e| anyhow!("Failed to serialize: {e}"))?;
+                let mut rows = Vec::new();
+                flatten(String::new(), &value, &mut rows);
+
+                let width = rows.iter().map(|(key, _)| key.len()).max().unwrap_or(0);
+                for (key, value) in rows {
+                    println!("{key:width$}  {value}");
+                }
+            }
+            OutputFormat::Json => {
+                let content = serde_json::to_string_pretty(self)
+                    .map_err(|e| anyhow!("Failed to serialize: {e}"))?;
+                println!("{content}");
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Flatten the JSON value into the rows of the table, nested keys are joined with dots.
+fn flatten(key: String, value: &Value, rows: &mut Vec<(String, String)>) {
+    let nested_key = |child: &dyn std::fmt::Display| {
+        if key.is_empty() {
+            child.to_string()
+        } else {
+            format!("{key}.{child}")
+        }
+    };
+    match value {
+        Value::Object(object) => {
+            for (child, value) in object {
+                flatten(nested_key(child), value, rows);
+            }
+        }
+        Value::Array(array) => {
+            for (index, value) in array.iter().enumerate() {
+                flatten(nested_key(&index), value, rows);
+            }
+        }
+        Value::String(value) => rows.push((key, value.clone())),
+        Value::Null => rows.push((key, String::new())),
+        value => rows.push((key, value.to_string())),
+    }
+}
+
+/// Name of the contract enum variant, e.g. 'Voting' for 'RoundState::Voting()'.
+pub fn enum_name(value: &impl std::fmt::Debug) -> String {
+    format!("{value:?}").trim_end_matches("()").to_string()
+}
+
+/// Format the contract timestamp (milliseconds since the epoch) as an RFC 3339 date.
+/// Timestamps after the year 9999 are returned as they are.
+pub fn timestamp_to_date(timestamp: u64) -> String {
+    if timestamp > MAX_DATE_TIMESTAMP {
+        return timestamp.to_string();
+    }
+    humantime::format_rfc3339_seconds(UNIX_EPOCH + Duration::from_millis(timestamp)).to_string()
+}
+
+/// Current time as the contract timestamp.
+pub fn now_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|now| now.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+/// Time left to the deadline, e.g. 'in 1h 5m', or 'passed' if the deadline is over.
+pub fn time_to_deadline(deadline: u64) -> String {
+    match deadline.checked_sub(now_timestamp()) {
+        Some(left) if left > 0 => format!(
+            "in {}",
+            humantime::format_duration(Duration::from_secs(left / 1000))
+        ),
+        _ => "passed".into(),
+    }
+}
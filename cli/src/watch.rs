@@ -0,0 +1,272 @@
+use std::{collections::HashSet, time::Duration};
+
+use aleph_client::{contract::event::ContractEvent, AccountId, Connection};
+use anyhow::{anyhow, Result};
+use futures::{
+    channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender},
+    StreamExt,
+};
+use serde::Serialize;
+use tokio::{process::Command, time::interval};
+use tracing::{info, warn};
+
+use crate::{
+    bright_disputes::BrightDisputes, bright_disputes_ink::ActionKind, config::OutputFormat, output,
+};
+
+/// Kind of the notice for the watched account.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum NoticeKind {
+    /// Account was assigned to the dispute as a juror or the judge
+    Assigned,
+    /// Juror is requested to vote or to reveal the vote
+    VoteRequested,
+    /// Judge is requested to count the votes
+    CountRequested,
+    /// Deadline of the account action is close
+    DeadlineWarning,
+}
+
+/// Notice for the watched account, made from the contract event or the deadline.
+#[derive(Clone, Debug, Serialize)]
+pub struct Notice {
+    pub kind: NoticeKind,
+    pub account: String,
+    pub dispute_id: u32,
+    pub action: Option<String>,
+    pub deadline: Option<u64>,
+    pub message: String,
+}
+
+impl Notice {
+    /// Print the notice, json format prints one notice per line.
+    pub fn print(&self, format: OutputFormat) -> Result<()> {
+        match format {
+            OutputFormat::Text => info!("{}", self.message),
+            OutputFormat::Table => println!(
+                "{:<16}  {:>6}  {}  {}",
+                format!("{:?}", self.kind),
+                self.dispute_id,
+                self.account,
+                self.message
+            ),
+            OutputFormat::Json => {
+                let content =
+                    serde_json::to_string(self).map_err(|e| anyhow!("Failed to serialize: {e}"))?;
+                println!("{content}");
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Hooks run for each notice.
+pub struct Hooks {
+    /// Shell command, notice is passed in the 'BRIGHT_DISPUTES_*' environment variables
+    pub command: Option<String>,
+    /// Local endpoint, notice is posted to it as JSON
+    pub webhook: Option<String>,
+}
+
+impl Hooks {
+    /// Run the hooks for the notice. Failed hooks are logged, they don't stop the watcher.
+    pub async fn run(&self, notice: &Notice) {
+        if let Some(command) = &self.command {
+            let status = Command::new("sh")
+                .arg("-c")
+                .arg(command)
+                .env("BRIGHT_DISPUTES_NOTICE", format!("{:?}", notice.kind))
+                .env("BRIGHT_DISPUTES_ACCOUNT", &notice.account)
+                .env("BRIGHT_DISPUTES_DISPUTE_ID", notice.dispute_id.to_string())
+                .env(
+                    "BRIGHT_DISPUTES_DEADLINE",
+                    notice.deadline.map(|d| d.to_string()).unwrap_or_default(),
+                )
+                .env("BRIGHT_DISPUTES_MESSAGE", &notice.message)
+                .status()
+                .await;
+            match status {
+                Ok(status) if status.success() => {}
+                Ok(status) => warn!("Hook command failed: {status}"),
+                Err(e) => warn!("Failed to run hook command: {e}"),
+            }
+        }
+        if let Some(webhook) = &self.webhook {
+            let response = reqwest::Client::new()
+                .post(webhook)
+                .json(notice)
+                .send()
+                .await
+                .and_then(|response| response.error_for_status());
+            if let Err(e) = response {
+                warn!("Failed to post notice to {webhook}: {e}");
+            }
+        }
+    }
+}
+
+/// Watch contract events and deadlines of the accounts actions. Notices are sent
+/// until the events subscription fails or the receiver is dropped.
+/// * `warn_before` - how long before the deadline the warning is sent,
+/// * `period` - how often deadlines are checked.
+pub async fn watch_notices(
+    bright_disputes: &BrightDisputes,
+    connection: &Connection,
+    accounts: &[AccountId],
+    warn_before: Duration,
+    period: Duration,
+    notices: UnboundedSender<Notice>,
+) -> Result<()> {
+    let (events_sender, events) = unbounded();
+    tokio::select! {
+        result = bright_disputes.listen_events(connection, events_sender) => result,
+        result = process_events(
+            bright_disputes,
+            connection,
+            accounts,
+            warn_before,
+            period,
+            events,
+            notices,
+        ) => result,
+    }
+}
+
+async fn process_events(
+    bright_disputes: &BrightDisputes,
+    connection: &Connection,
+    accounts: &[AccountId],
+    warn_before: Duration,
+    period: Duration,
+    mut events: UnboundedReceiver<Result<ContractEvent>>,
+    notices: UnboundedSender<Notice>,
+) -> Result<()> {
+    let mut warned = HashSet::new();
+    let mut ticks = interval(period);
+    loop {
+        let new_notices = tokio::select! {
+            event = events.next() => {
+                let event = event.ok_or_else(|| anyhow!("Contract events subscription closed!"))??;
+                event_notices(bright_disputes, connection, accounts, &event).await?
+            }
+            _ = ticks.tick() => {
+                deadline_notices(bright_disputes, connection, accounts, warn_before, &mut warned)
+                    .await?
+            }
+        };
+        for notice in new_notices {
+            notices
+                .unbounded_send(notice)
+                .map_err(|_| anyhow!("Notices receiver closed!"))?;
+        }
+    }
+}
+
+/// Make notice from the event, if it is emitted for one of the accounts.
+async fn event_notices(
+    bright_disputes: &BrightDisputes,
+    connection: &Connection,
+    accounts: &[AccountId],
+    event: &ContractEvent,
+) -> Result<Vec<Notice>> {
+    let field = |name: &str| event.data.get(name).map(|value| value.to_string());
+    // Role is known from the assignment events, action requests are sent to juries and the judge.
+    let (account_field, assigned_role) = match event.name.as_deref() {
+        Some("JurorAssigned") => ("juror_id", Some("Juror")),
+        Some("JudgeAssigned") => ("judge_id", Some("Judge")),
+        Some("ActionRequested") => ("juror_id", None),
+        _ => return Ok(Vec::new()),
+    };
+    let dispute_id = match field("dispute_id").and_then(|id| id.parse::<u32>().ok()) {
+        Some(dispute_id) => dispute_id,
+        None => return Ok(Vec::new()),
+    };
+    let account = match accounts
+        .iter()
+        .find(|account| Some(account.to_string()) == field(account_field))
+    {
+        Some(account) => account,
+        None => return Ok(Vec::new()),
+    };
+
+    let action = bright_disputes
+        .account_pending_actions(connection, account)
+        .await?
+        .into_iter()
+        .find(|(id, _, _)| *id == dispute_id);
+    let deadline = action.as_ref().and_then(|(_, _, deadline)| *deadline);
+    let deadline_text = deadline
+        .map(|deadline| format!(", deadline: {}", output::timestamp_to_date(deadline)))
+        .unwrap_or_default();
+
+    let (kind, message) = match (assigned_role, action.as_ref().map(|(_, action, _)| action)) {
+        (Some(role), _) => (
+            NoticeKind::Assigned,
+            format!("{role} {account} is assigned to the dispute {dispute_id}{deadline_text}"),
+        ),
+        (None, Some(ActionKind::CountTheVotes())) => (
+            NoticeKind::CountRequested,
+            format!("{account} is requested to count the votes in the dispute {dispute_id}{deadline_text}"),
+        ),
+        (None, Some(ActionKind::RevealVote())) => (
+            NoticeKind::VoteRequested,
+            format!("{account} is requested to reveal the vote in the dispute {dispute_id}{deadline_text}"),
+        ),
+        (None, _) => (
+            NoticeKind::VoteRequested,
+            format!("{account} is requested to vote in the dispute {dispute_id}{deadline_text}"),
+        ),
+    };
+    Ok(vec![Notice {
+        kind,
+        account: account.to_string(),
+        dispute_id,
+        action: action.map(|(_, action, _)| output::enum_name(&action)),
+        deadline,
+        message,
+    }])
+}
+
+/// Make warnings for the pending actions, which deadline is closer than `warn_before`.
+/// Each deadline is warned once, `warned` keeps already warned ones.
+async fn deadline_notices(
+    bright_disputes: &BrightDisputes,
+    connection: &Connection,
+    accounts: &[AccountId],
+    warn_before: Duration,
+    warned: &mut HashSet<(AccountId, u32, u64)>,
+) -> Result<Vec<Notice>> {
+    let now = output::now_timestamp();
+    let warn_from = now + warn_before.as_millis() as u64;
+
+    let mut notices = Vec::new();
+    for account in accounts {
+        let actions = bright_disputes
+            .account_pending_actions(connection, account)
+            .await?;
+        for (dispute_id, action, deadline) in actions {
+            let deadline = match deadline {
+                Some(deadline) if deadline > now && deadline <= warn_from => deadline,
+                _ => continue,
+            };
+            if !warned.insert((account.clone(), dispute_id, deadline)) {
+                continue;
+            }
+            notices.push(Notice {
+                kind: NoticeKind::DeadlineWarning,
+                account: account.to_string(),
+                dispute_id,
+                action: Some(output::enum_name(&action)),
+                deadline: Some(deadline),
+                message: format!(
+                    "{account}: {} in the dispute {dispute_id}, deadline: {} ({})",
+                    output::enum_name(&action),
+                    output::timestamp_to_date(deadline),
+                    output::time_to_deadline(deadline)
+                ),
+            });
+        }
+    }
+    Ok(notices)
+}
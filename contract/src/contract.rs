@@ -1,70 +1,209 @@
-#[ink::contract(env = baby_liminal_extension::ink::BabyLiminalEnvironment)]
+#[cfg_attr(
+    feature = "baby-liminal",
+    ink::contract(env = baby_liminal_extension::ink::BabyLiminalEnvironment)
+)]
+#[cfg_attr(not(feature = "baby-liminal"), ink::contract)]
 pub mod bright_disputes {
 
     use ink::{
-        prelude::{string::String, vec, vec::Vec},
+        codegen::{EmitEvent, Env},
+        prelude::{string::String, vec::Vec},
         storage::Mapping,
     };
 
-    use ark_serialize::CanonicalSerialize;
-
     use crate::{
-        dispute::{Dispute, DisputeResult},
-        dispute_round::DisputeRound,
+        dispute::{
+            DefaultJudgmentRules, Dispute, DisputeFilter, DisputeResult, DisputeState,
+            MajorityThreshold, VotingMode,
+        },
+        dispute_round::{DisputeRound, DisputeRoundEvent, RoundState},
         error::BrightDisputesError,
+        evidence::Evidence,
         juror::{JuriesMap, Juror},
+        juror_stats::JurorStats,
         types::{AccountsVec, DisputeId, Proof, PublicKey, Result, VoteHash},
+        verifier::{Relation, Verifier},
         vote::Vote,
-        VERDICT_NEGATIVE_VK_IDENTIFIER, VERDICT_NONE_VK_IDENTIFIER, VERDICT_POSITIVE_VK_IDENTIFIER,
-        VOTE_VK_IDENTIFIER,
     };
 
-    use baby_liminal_extension::BabyLiminalExtension;
-
-    use liminal_ark_relations::{
-        disputes::{
-            VerdictNegativeRelationWithPublicInput, VerdictNoneRelationWithPublicInput,
-            VerdictPositiveRelationWithPublicInput, VerdictRelation, VoteRelationWithPublicInput,
-        },
-        environment::CircuitField,
-    };
+    #[cfg(feature = "baby-liminal")]
+    use crate::verifier::BabyLiminalVerifier;
+    #[cfg(all(feature = "mock-verifier", not(feature = "baby-liminal")))]
+    use crate::verifier::MockVerifier;
+    #[cfg(not(any(feature = "baby-liminal", feature = "mock-verifier")))]
+    use crate::verifier::NoVerifier;
 
     use scale::{Decode, Encode};
 
     #[ink(event)]
     pub struct DisputeRaised {
+        #[ink(topic)]
         id: DisputeId,
+        #[ink(topic)]
         owner_id: AccountId,
+        #[ink(topic)]
         defendant_id: AccountId,
     }
 
     #[ink(event)]
     pub struct DisputeClosed {
+        #[ink(topic)]
         id: DisputeId,
     }
 
     #[ink(event)]
     pub struct DefendantConfirmDispute {
+        #[ink(topic)]
         id: DisputeId,
+        #[ink(topic)]
         defendant_id: AccountId,
     }
 
+    /// Dispute has ended, `None` result means that majority of votes wasn't reached.
     #[ink(event)]
     pub struct DisputeResultEvent {
+        #[ink(topic)]
         id: DisputeId,
-        result: DisputeResult,
+        result: Option<DisputeResult>,
     }
 
-    #[derive(Eq, PartialEq, Debug, Decode, Encode)]
-    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
-    pub enum Relation {
-        Vote,
-        VerdictNegative,
-        VerdictNone,
-        VerdictPositive,
+    #[ink(event)]
+    pub struct JurorAssigned {
+        #[ink(topic)]
+        dispute_id: DisputeId,
+        #[ink(topic)]
+        juror_id: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct JudgeAssigned {
+        #[ink(topic)]
+        dispute_id: DisputeId,
+        #[ink(topic)]
+        judge_id: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct JurorConfirmed {
+        #[ink(topic)]
+        dispute_id: DisputeId,
+        #[ink(topic)]
+        juror_id: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct JudgeConfirmed {
+        #[ink(topic)]
+        dispute_id: DisputeId,
+        #[ink(topic)]
+        judge_id: AccountId,
+    }
+
+    /// Juror is requested to vote, or judge is requested to count the votes.
+    #[ink(event)]
+    pub struct ActionRequested {
+        #[ink(topic)]
+        dispute_id: DisputeId,
+        #[ink(topic)]
+        juror_id: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct VoteCast {
+        #[ink(topic)]
+        dispute_id: DisputeId,
+        #[ink(topic)]
+        juror_id: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct VotesCounted {
+        #[ink(topic)]
+        dispute_id: DisputeId,
+        #[ink(topic)]
+        judge_id: AccountId,
+        verdict: Verdict,
+    }
+
+    #[ink(event)]
+    pub struct RoundStateChanged {
+        #[ink(topic)]
+        dispute_id: DisputeId,
+        state: RoundState,
+        deadline: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct RoundDeadlineReached {
+        #[ink(topic)]
+        dispute_id: DisputeId,
+        state: RoundState,
     }
 
-    #[derive(Eq, PartialEq, Debug, Decode, Encode)]
+    #[ink(event)]
+    pub struct JurorBanned {
+        #[ink(topic)]
+        dispute_id: DisputeId,
+        #[ink(topic)]
+        juror_id: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct DepositPaid {
+        #[ink(topic)]
+        dispute_id: DisputeId,
+        #[ink(topic)]
+        account_id: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct SettlementProposed {
+        #[ink(topic)]
+        dispute_id: DisputeId,
+        #[ink(topic)]
+        proposed_by: AccountId,
+        owner_share: u8,
+    }
+
+    #[ink(event)]
+    pub struct DisputeSettled {
+        #[ink(topic)]
+        dispute_id: DisputeId,
+        owner_share: u8,
+    }
+
+    /// Dispute ended in favour of the owner, because the defendant didn't
+    /// confirm it before the response deadline.
+    #[ink(event)]
+    pub struct DefaultJudgmentIssued {
+        #[ink(topic)]
+        dispute_id: DisputeId,
+        #[ink(topic)]
+        owner_id: AccountId,
+        #[ink(topic)]
+        defendant_id: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct JurorSuspended {
+        #[ink(topic)]
+        juror_id: AccountId,
+        suspended_until: Timestamp,
+    }
+
+    /// Juror revealed the vote, in the commit-reveal voting mode, or abstained in the
+    /// private voting mode.
+    #[ink(event)]
+    pub struct VoteRevealed {
+        #[ink(topic)]
+        dispute_id: DisputeId,
+        #[ink(topic)]
+        juror_id: AccountId,
+        vote: u8,
+    }
+
+    #[derive(Clone, Eq, PartialEq, Debug, Decode, Encode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
     pub enum Verdict {
         None,
@@ -72,14 +211,37 @@ pub mod bright_disputes {
         Positive,
     }
 
+    /// Action, which account needs to take in the dispute.
+    #[derive(Clone, Eq, PartialEq, Debug, Decode, Encode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum ActionKind {
+        ConfirmDefendant,
+        ConfirmJuror,
+        ConfirmJudge,
+        Vote,
+        CountTheVotes,
+        ProcessDisputeRound,
+        RequestDefaultJudgment,
+        RevealVote,
+    }
+
     /// Main contract storage
     #[ink(storage)]
     #[derive(Default)]
     pub struct BrightDisputes {
         last_dispute_id: DisputeId,
-        juries_pool: Vec<AccountId>,
+        juries_pool: Mapping<u32, AccountId>,
+        juries_pool_indexes: Mapping<AccountId, u32>,
+        juries_pool_len: u32,
         juries: Mapping<AccountId, Juror>,
         disputes: Mapping<DisputeId, Dispute>,
+        account_disputes: Mapping<AccountId, Vec<DisputeId>>,
+        evidences: Mapping<(DisputeId, AccountId), Vec<Evidence>>,
+        juries_stats: Mapping<AccountId, JurorStats>,
+        suspensions: Mapping<AccountId, Timestamp>,
+        suspension_bans_limit: u32,
+        suspension_time: Option<Timestamp>,
+        default_judgment_rules: Option<DefaultJudgmentRules>,
     }
 
     impl JuriesMap for BrightDisputes {
@@ -105,17 +267,93 @@ pub mod bright_disputes {
         fn update_juror(&mut self, juror: Juror) {
             self.juries.insert(juror.id(), &juror);
         }
+
+        fn emit_round_event(&mut self, event: DisputeRoundEvent) {
+            match event {
+                // Assigned juries and judge are indexed with the dispute once, on assignment.
+                DisputeRoundEvent::JurorAssigned(dispute_id, juror_id) => {
+                    self.add_account_dispute(juror_id, dispute_id);
+                    self.env().emit_event(JurorAssigned {
+                        dispute_id,
+                        juror_id,
+                    })
+                }
+                DisputeRoundEvent::JudgeAssigned(dispute_id, judge_id) => {
+                    self.add_account_dispute(judge_id, dispute_id);
+                    self.env().emit_event(JudgeAssigned {
+                        dispute_id,
+                        judge_id,
+                    })
+                }
+                DisputeRoundEvent::ActionRequested(dispute_id, juror_id) => {
+                    self.env().emit_event(ActionRequested {
+                        dispute_id,
+                        juror_id,
+                    })
+                }
+                DisputeRoundEvent::StateChanged(dispute_id, state, deadline) => {
+                    self.env().emit_event(RoundStateChanged {
+                        dispute_id,
+                        state,
+                        deadline,
+                    })
+                }
+            }
+        }
     }
 
     impl BrightDisputes {
-        /// Constructor
+        const SUSPENSION_BANS_LIMIT: u32 = 3;
+        const SUSPENSION_TIME: Timestamp = 30 * 24 * 3600 * 1000;
+        const DISPUTES_PAGE_LIMIT: u32 = 50;
+        const SETTLEMENT_JURIES_FEE_PERCENT: Balance = 10;
+        /// Percentage of the deposit share, which is paid to the juror who abstained.
+        const ABSTAIN_FEE_PERCENT: Balance = 50;
+        const EVIDENCE_MAX_SIZE: usize = 4096;
+        const EVIDENCES_PER_PARTY_LIMIT: usize = 5;
+
+        /// Constructor, default judgments are disabled.
         #[ink(constructor)]
         pub fn new() -> Self {
+            Self::new_with_suspension_rules(
+                Self::SUSPENSION_BANS_LIMIT,
+                Some(Self::SUSPENSION_TIME),
+            )
+        }
+
+        /// Constructor with custom suspension rules. Juror is suspended after every
+        /// `suspension_bans_limit` bans. If `suspension_time` is `None`, the
+        /// suspension is permanent. Default judgments are disabled.
+        #[ink(constructor)]
+        pub fn new_with_suspension_rules(
+            suspension_bans_limit: u32,
+            suspension_time: Option<Timestamp>,
+        ) -> Self {
+            Self::new_with_rules(suspension_bans_limit, suspension_time, None)
+        }
+
+        /// Constructor with custom suspension and default judgment rules. If
+        /// `default_judgment_rules` is `None`, default judgments are disabled.
+        #[ink(constructor)]
+        pub fn new_with_rules(
+            suspension_bans_limit: u32,
+            suspension_time: Option<Timestamp>,
+            default_judgment_rules: Option<DefaultJudgmentRules>,
+        ) -> Self {
             Self {
                 last_dispute_id: 0,
-                juries_pool: Vec::new(),
+                juries_pool: Mapping::default(),
+                juries_pool_indexes: Mapping::default(),
+                juries_pool_len: 0,
                 juries: Mapping::default(),
                 disputes: Mapping::default(),
+                account_disputes: Mapping::default(),
+                evidences: Mapping::default(),
+                juries_stats: Mapping::default(),
+                suspensions: Mapping::default(),
+                suspension_bans_limit,
+                suspension_time,
+                default_judgment_rules,
             }
         }
 
@@ -139,10 +377,102 @@ pub mod bright_disputes {
                 .collect()
         }
 
+        /// Get page of disputes, which meet the filter conditions. `offset` is
+        /// the number of matching disputes to skip, `limit` is capped at
+        /// `DISPUTES_PAGE_LIMIT`. Filtering by an account uses the per account
+        /// index, so it doesn't scan all disputes.
+        #[ink(message)]
+        pub fn get_disputes(&self, offset: u32, limit: u32, filter: DisputeFilter) -> Vec<Dispute> {
+            let limit = limit.min(Self::DISPUTES_PAGE_LIMIT) as usize;
+            let mut disputes = Vec::new();
+            if limit == 0 {
+                return disputes;
+            }
+
+            // Disputes of the account with the shortest index are checked, all
+            // account conditions of the filter are checked for each dispute.
+            let disputes_ids: Vec<DisputeId> = match filter
+                .accounts()
+                .into_iter()
+                .map(|account_id| self.get_account_disputes(account_id))
+                .min_by_key(|disputes_ids| disputes_ids.len())
+            {
+                Some(disputes_ids) => disputes_ids,
+                None => (1..=self.last_dispute_id).collect(),
+            };
+
+            // Stop once 'offset + limit' matching disputes are found.
+            let mut skipped = 0;
+            for id in disputes_ids {
+                let dispute = match self.disputes.get(id) {
+                    Some(dispute) if filter.matches(&dispute) => dispute,
+                    _ => continue,
+                };
+                if skipped < offset {
+                    skipped += 1;
+                    continue;
+                }
+                disputes.push(dispute);
+                if disputes.len() == limit {
+                    break;
+                }
+            }
+            disputes
+        }
+
+        /// Get ids of disputes, where account is an owner, defendant, judge or juror.
+        #[ink(message)]
+        pub fn get_account_disputes(&self, account_id: AccountId) -> Vec<DisputeId> {
+            self.account_disputes.get(account_id).unwrap_or_default()
+        }
+
+        /// Get actions, which account needs to take in its disputes, together
+        /// with the deadline of the current dispute round, or the response
+        /// deadline of the defendant if the dispute is not confirmed yet.
+        #[ink(message)]
+        pub fn pending_actions(
+            &self,
+            account_id: AccountId,
+        ) -> Vec<(DisputeId, ActionKind, Option<Timestamp>)> {
+            let now = self.env().block_timestamp();
+            self.get_account_disputes(account_id)
+                .into_iter()
+                .flat_map(|id| self.disputes.get(id))
+                .flat_map(|dispute| {
+                    let deadline = match dispute.dispute_round() {
+                        Some(round) => Some(round.state_deadline()),
+                        None => self
+                            .default_judgment_rules
+                            .as_ref()
+                            .map(|rules| dispute.response_deadline(rules.response_time)),
+                    };
+                    self.pending_action(&dispute, account_id, now)
+                        .map(|action| (dispute.id(), action, deadline))
+                })
+                .collect()
+        }
+
         /// Get juries pool
         #[ink(message)]
         pub fn get_juries_pool(&self) -> Vec<AccountId> {
-            self.juries_pool.clone()
+            (0..self.juries_pool_len)
+                .flat_map(|index| self.juries_pool.get(index))
+                .collect()
+        }
+
+        /// Get reputation of the juror, collected across all disputes.
+        #[ink(message)]
+        pub fn get_juror_stats(&self, juror_id: AccountId) -> JurorStats {
+            self.juries_stats.get(juror_id).unwrap_or_default()
+        }
+
+        /// Get the time until juror is suspended from registering as an active
+        /// juror. Permanent suspension lasts until `Timestamp::MAX`.
+        #[ink(message)]
+        pub fn get_juror_suspension(&self, juror_id: AccountId) -> Option<Timestamp> {
+            self.suspensions
+                .get(juror_id)
+                .filter(|&until| until > self.env().block_timestamp())
         }
 
         /// Remove single dispute by id
@@ -152,6 +482,14 @@ pub mod bright_disputes {
             dispute.assert_dispute_remove()?;
             self.disputes.remove(dispute_id);
 
+            let mut accounts = Vec::from([dispute.owner(), dispute.defendant()]);
+            accounts.extend(dispute.judge());
+            accounts.extend(dispute.juries());
+            accounts.extend(dispute.banned());
+            for account_id in accounts {
+                self.remove_account_dispute(account_id, dispute_id);
+            }
+
             self.env().emit_event(DisputeClosed { id: dispute_id });
 
             Ok(())
@@ -165,11 +503,46 @@ pub mod bright_disputes {
             defendant_id: AccountId,
             escrow: Balance,
         ) -> Result<DisputeId> {
+            let voting_mode = if self.verifier().is_supported() {
+                VotingMode::Private
+            } else {
+                VotingMode::CommitReveal
+            };
+            self.create_dispute_with_voting_mode(
+                owner_link,
+                defendant_id,
+                escrow,
+                voting_mode,
+                None,
+            )
+        }
+
+        /// Create new dispute with the selected voting mode. If `majority_threshold`
+        /// is `None`, 70% of the votes are needed to reach the majority.
+        #[ink(message, payable)]
+        pub fn create_dispute_with_voting_mode(
+            &mut self,
+            owner_link: String,
+            defendant_id: AccountId,
+            escrow: Balance,
+            voting_mode: VotingMode,
+            majority_threshold: Option<MajorityThreshold>,
+        ) -> Result<DisputeId> {
+            if voting_mode == VotingMode::Private && !self.verifier().is_supported() {
+                return Err(BrightDisputesError::VotingModeNotSupported);
+            }
             self.assert_transferred(escrow)?;
             let owner_id = ink::env::caller::<ink::env::DefaultEnvironment>();
             self.last_dispute_id = self.generate_dispute_id()?;
-            let dispute = Dispute::create(self.last_dispute_id, owner_link, defendant_id, escrow);
+            let mut dispute =
+                Dispute::create(self.last_dispute_id, owner_link, defendant_id, escrow);
+            dispute.set_voting_mode(voting_mode)?;
+            if let Some(majority_threshold) = majority_threshold {
+                dispute.set_majority_threshold(majority_threshold)?;
+            }
             self.update_dispute(dispute);
+            self.add_account_dispute(owner_id, self.last_dispute_id);
+            self.add_account_dispute(defendant_id, self.last_dispute_id);
 
             self.env().emit_event(DisputeRaised {
                 id: self.last_dispute_id,
@@ -193,13 +566,37 @@ pub mod bright_disputes {
             dispute.confirm_defendant(defendant_link)?;
             dispute.set_dispute_round(DisputeRound::create(self.env().block_timestamp(), None));
             dispute.increment_deposit();
-            self.update_dispute(dispute);
 
             self.env().emit_event(DefendantConfirmDispute {
                 id,
                 defendant_id: ink::env::caller::<ink::env::DefaultEnvironment>(),
             });
+            self.emit_new_dispute_round(&dispute);
+            self.update_dispute(dispute);
+
+            Ok(())
+        }
+
+        /// Owner requests the default judgment, when the defendant hasn't
+        /// confirmed the dispute before the response deadline. Dispute ends
+        /// in favour of the owner without a jury and the escrow is returned.
+        #[ink(message)]
+        pub fn request_default_judgment(&mut self, dispute_id: DisputeId) -> Result<()> {
+            let mut dispute = self.get_dispute_or_assert(dispute_id)?;
+            self.default_judgment_rules
+                .as_ref()
+                .ok_or(BrightDisputesError::DefaultJudgmentNotAllowed)?
+                .assert_allowed(&dispute, self.env().block_timestamp())?;
+            dispute.issue_default_judgment()?;
+            self.pay_out(dispute_id, dispute.owner(), dispute.deposit())?;
+            dispute.close_dispute()?;
 
+            self.env().emit_event(DefaultJudgmentIssued {
+                dispute_id,
+                owner_id: dispute.owner(),
+                defendant_id: dispute.defendant(),
+            });
+            self.update_dispute(dispute);
             Ok(())
         }
 
@@ -229,7 +626,7 @@ pub mod bright_disputes {
             Ok(())
         }
 
-        /// Voting, only juror can do it.
+        /// Voting, only juror can do it. The vote can be changed with `change_vote`.
         #[ink(message)]
         pub fn vote(
             &mut self,
@@ -242,21 +639,126 @@ pub mod bright_disputes {
             let mut dispute = self.get_dispute_or_assert(dispute_id)?;
             let mut juror = self.get_juror_or_assert(caller)?;
 
-            let input =
-                VoteRelationWithPublicInput::new(vote, dispute.votes_hash(), hash_of_all_votes)
-                    .serialize_public_input();
+            dispute.assert_voting_mode(VotingMode::Private)?;
+            self.verifier()
+                .verify_vote(proof, vote, dispute.votes_hash(), hash_of_all_votes)?;
+
+            dispute.vote(Vote::create(caller, vote), hash_of_all_votes)?;
+            juror.action_done(dispute.id())?;
+            self.update_juror(juror);
+            self.update_dispute(dispute);
+
+            self.env().emit_event(VoteCast {
+                dispute_id,
+                juror_id: caller,
+            });
+            Ok(())
+        }
+
+        /// Abstain from the vote (private voting mode), only juror who hasn't voted can do it.
+        /// Juror is not counted to the majority, is never banned for it and gets
+        /// `ABSTAIN_FEE_PERCENT` of the regular deposit share. Unlike the vote, abstain is public.
+        #[ink(message)]
+        pub fn abstain(&mut self, dispute_id: DisputeId) -> Result<()> {
+            let caller = ink::env::caller::<ink::env::DefaultEnvironment>();
+            let mut dispute = self.get_dispute_or_assert(dispute_id)?;
+            let mut juror = self.get_juror_or_assert(caller)?;
+
+            dispute.abstain(caller)?;
+            juror.action_done(dispute.id())?;
+            self.update_juror(juror);
+            self.update_dispute(dispute);
+
+            self.env().emit_event(VoteRevealed {
+                dispute_id,
+                juror_id: caller,
+                vote: Vote::ABSTAIN,
+            });
+            Ok(())
+        }
+
+        /// Change the vote (private voting mode), only juror who has voted can do it, until
+        /// the voting ends. The vote is encrypted with a fresh `public_key`, which the judge
+        /// uses to decrypt it. With the key of the previous vote the difference of the
+        /// encrypted votes would show if the vote has changed.
+        #[ink(message)]
+        pub fn change_vote(
+            &mut self,
+            dispute_id: DisputeId,
+            public_key: PublicKey,
+            vote: VoteHash,
+            hash_of_all_votes: VoteHash,
+            proof: Proof,
+        ) -> Result<()> {
+            let caller = ink::env::caller::<ink::env::DefaultEnvironment>();
+            let mut dispute = self.get_dispute_or_assert(dispute_id)?;
+            let juror = self.get_juror_or_assert(caller)?;
+
+            dispute.assert_voting_mode(VotingMode::Private)?;
+            if juror.public_key() == Some(public_key.clone()) {
+                return Err(BrightDisputesError::VotePublicKeyReused);
+            }
+            self.verifier()
+                .verify_vote(proof, vote, dispute.votes_hash(), hash_of_all_votes)?;
 
-            // Verify a proof.
-            self.env().extension().verify(
-                VOTE_VK_IDENTIFIER,
-                proof,
-                Self::serialize::<Vec<CircuitField>>(input.as_ref()),
+            dispute.change_vote(
+                Vote::create_with_public_key(caller, vote, public_key),
+                hash_of_all_votes,
             )?;
+            self.update_dispute(dispute);
 
-            dispute.vote(Vote::create(caller, vote), hash_of_all_votes)?;
+            self.env().emit_event(VoteCast {
+                dispute_id,
+                juror_id: caller,
+            });
+            Ok(())
+        }
+
+        /// Commit the hash of the vote (commit-reveal voting mode), only juror can do it.
+        /// `commitment` is a blake2 hash of the scale encoded `(juror, vote, salt)`.
+        #[ink(message)]
+        pub fn commit_vote(&mut self, dispute_id: DisputeId, commitment: VoteHash) -> Result<()> {
+            let caller = ink::env::caller::<ink::env::DefaultEnvironment>();
+            let mut dispute = self.get_dispute_or_assert(dispute_id)?;
+            let mut juror = self.get_juror_or_assert(caller)?;
+
+            let revote = dispute.has_voted(caller);
+            dispute.commit_vote(Vote::create(caller, commitment))?;
+            if !revote {
+                juror.action_done(dispute.id())?;
+                self.update_juror(juror);
+            }
+            self.update_dispute(dispute);
+
+            self.env().emit_event(VoteCast {
+                dispute_id,
+                juror_id: caller,
+            });
+            Ok(())
+        }
+
+        /// Reveal the committed vote (commit-reveal voting mode), only juror can do it.
+        #[ink(message)]
+        pub fn reveal_vote(
+            &mut self,
+            dispute_id: DisputeId,
+            vote: u8,
+            salt: [u8; 32],
+        ) -> Result<()> {
+            let caller = ink::env::caller::<ink::env::DefaultEnvironment>();
+            let mut dispute = self.get_dispute_or_assert(dispute_id)?;
+            let mut juror = self.get_juror_or_assert(caller)?;
+
+            dispute.reveal_vote(caller, vote, salt)?;
             juror.action_done(dispute.id())?;
             self.update_juror(juror);
             self.update_dispute(dispute);
+
+            self.env().emit_event(VoteRevealed {
+                dispute_id,
+                juror_id: caller,
+                vote,
+            });
             Ok(())
         }
 
@@ -264,7 +766,8 @@ pub mod bright_disputes {
         /// from this pool to participate in disputes.
         #[ink(message)]
         pub fn register_as_an_active_juror(&mut self) -> Result<()> {
-            self.add_to_juries_pool(ink::env::caller::<ink::env::DefaultEnvironment>())?;
+            let caller = ink::env::caller::<ink::env::DefaultEnvironment>();
+            self.add_to_juries_pool(caller)?;
             Ok(())
         }
 
@@ -293,6 +796,11 @@ pub mod bright_disputes {
 
             dispute.increment_deposit();
             self.update_dispute(dispute);
+
+            self.env().emit_event(JurorConfirmed {
+                dispute_id,
+                juror_id: caller,
+            });
             Ok(())
         }
 
@@ -313,10 +821,38 @@ pub mod bright_disputes {
 
             dispute.increment_deposit();
             self.update_dispute(dispute);
+
+            self.env().emit_event(JudgeConfirmed {
+                dispute_id,
+                judge_id: caller,
+            });
             Ok(())
         }
 
+        /// Get thresholds of the verdict for the recorded votes (private voting mode):
+        /// `(votes_minimum, votes_maximum)`.
+        #[ink(message)]
+        pub fn get_verdict_thresholds(&self, dispute_id: DisputeId) -> Result<(u8, u8)> {
+            let dispute = self.get_dispute_or_assert(dispute_id)?;
+            dispute.assert_voting_mode(VotingMode::Private)?;
+
+            let number_of_votes = dispute.number_of_votes();
+            let majority_threshold = dispute.majority_threshold();
+            Ok((
+                majority_threshold.votes_minimum(number_of_votes) as u8,
+                majority_threshold.votes_maximum(number_of_votes) as u8,
+            ))
+        }
+
+        /// Issue the verdict proven by the judge (private voting mode). Single votes
+        /// are not known on-chain, so the judge lists the juries who voted against
+        /// the verdict (`jurors_banned`) and proves it with two proofs: `proof` shows
+        /// that the rest of the votes agree with the verdict and `minority_proof` that
+        /// the votes of banned juries are against it. For the verdict `None` nobody is
+        /// banned and `proof` counts all votes. The list is as trustworthy as the
+        /// verdict itself, the judge can't add juries to it or leave them out.
         #[ink(message)]
+        #[allow(clippy::too_many_arguments)]
         pub fn issue_the_verdict(
             &mut self,
             dispute_id: DisputeId,
@@ -324,51 +860,104 @@ pub mod bright_disputes {
             votes_minimum: u8,
             verdict: Verdict,
             hashed_votes: VoteHash,
-            jurors_banned: AccountsVec,
             proof: Proof,
+            jurors_banned: AccountsVec,
+            minority_proof: Proof,
         ) -> Result<()> {
             let mut dispute = self.get_dispute_or_assert(dispute_id)?;
 
+            dispute.assert_voting_mode(VotingMode::Private)?;
             dispute.assert_can_count_the_votes()?;
             dispute.assert_judge(ink::env::caller::<ink::env::DefaultEnvironment>())?;
 
-            // Verify a proof.
-            let (input, identifier) = match verdict {
-                Verdict::Positive => (
-                    VerdictPositiveRelationWithPublicInput::new(
-                        votes_minimum,
-                        VerdictRelation::Positive as u8,
-                        hashed_votes,
-                    )
-                    .serialize_public_input(),
-                    VERDICT_POSITIVE_VK_IDENTIFIER,
-                ),
-                Verdict::Negative => (
-                    VerdictNegativeRelationWithPublicInput::new(
-                        votes_maximum,
-                        VerdictRelation::Negative as u8,
-                        hashed_votes,
-                    )
-                    .serialize_public_input(),
-                    VERDICT_NEGATIVE_VK_IDENTIFIER,
-                ),
-                Verdict::None => (
-                    VerdictNoneRelationWithPublicInput::new(
+            // Verdict thresholds have to match the recorded votes.
+            let number_of_votes = dispute.number_of_votes();
+            let majority_threshold = dispute.majority_threshold();
+            if votes_minimum as u32 != majority_threshold.votes_minimum(number_of_votes)
+                || votes_maximum as u32 != majority_threshold.votes_maximum(number_of_votes)
+            {
+                return Err(BrightDisputesError::InvalidMajorityThreshold);
+            }
+            Self::assert_verdict_has_votes(&dispute, &verdict)?;
+
+            // Only juries who voted can be banned, nobody is banned without a verdict.
+            dispute.assert_jurors_banned(&jurors_banned)?;
+            if verdict == Verdict::None && !jurors_banned.is_empty() {
+                return Err(BrightDisputesError::InvalidJurorsBanned);
+            }
+
+            // Verdict has to be made for the recorded votes.
+            let verifier = self.verifier();
+            let votes_hash = |votes: &[VoteHash]| {
+                verifier
+                    .verdict_votes_hash(votes)
+                    .ok_or(BrightDisputesError::VotingModeNotSupported)
+            };
+            let votes: Vec<VoteHash> = dispute.votes().iter().map(|vote| vote.vote()).collect();
+            if hashed_votes != votes_hash(&votes)? {
+                return Err(BrightDisputesError::InvalidVotesHash);
+            }
+
+            match verdict {
+                Verdict::None => {
+                    self.verifier().verify_verdict(
+                        proof,
+                        Relation::VerdictNone,
                         votes_minimum,
                         votes_maximum,
-                        VerdictRelation::None as u8,
                         hashed_votes,
-                    )
-                    .serialize_public_input(),
-                    VERDICT_NONE_VK_IDENTIFIER,
-                ),
-            };
-
-            self.env().extension().verify(
-                identifier,
-                proof,
-                Self::serialize::<Vec<CircuitField>>(input.as_ref()),
-            )?;
+                    )?;
+                }
+                Verdict::Positive | Verdict::Negative => {
+                    let (minority, majority): (Vec<Vote>, Vec<Vote>) = dispute
+                        .votes()
+                        .into_iter()
+                        .partition(|vote| jurors_banned.contains(&vote.juror()));
+                    let majority: Vec<VoteHash> = majority.iter().map(|v| v.vote()).collect();
+                    let minority: Vec<VoteHash> = minority.iter().map(|v| v.vote()).collect();
+                    let majority_hash = votes_hash(&majority)?;
+                    let minority_hash = votes_hash(&minority)?;
+
+                    // Votes of the majority are all for the verdict and there are enough
+                    // of them, votes of the minority are all against it.
+                    let (majority_input, minority_input) = match verdict {
+                        Verdict::Positive => {
+                            if majority.len() < votes_minimum as usize {
+                                return Err(BrightDisputesError::InvalidJurorsBanned);
+                            }
+                            (
+                                (Relation::VerdictPositive, majority.len() as u8, 0),
+                                (Relation::VerdictNegative, 0, 0),
+                            )
+                        }
+                        _ => {
+                            if minority.len() > votes_maximum as usize {
+                                return Err(BrightDisputesError::InvalidJurorsBanned);
+                            }
+                            (
+                                (Relation::VerdictNegative, 0, 0),
+                                (Relation::VerdictPositive, minority.len() as u8, 0),
+                            )
+                        }
+                    };
+                    let (relation, minimum, maximum) = majority_input;
+                    self.verifier().verify_verdict(
+                        proof,
+                        relation,
+                        minimum,
+                        maximum,
+                        majority_hash,
+                    )?;
+                    let (relation, minimum, maximum) = minority_input;
+                    self.verifier().verify_verdict(
+                        minority_proof,
+                        relation,
+                        minimum,
+                        maximum,
+                        minority_hash,
+                    )?;
+                }
+            }
 
             // Mark judge work as done.
             let judge_id = dispute.judge().unwrap();
@@ -376,15 +965,48 @@ pub mod bright_disputes {
             judge.action_done(dispute.id())?;
             self.update_juror(judge);
 
-            // End the dispute
+            self.env().emit_event(VotesCounted {
+                dispute_id,
+                judge_id,
+                verdict: verdict.clone(),
+            });
+
+            self.apply_verdict(&mut dispute, verdict, jurors_banned)?;
+            self.update_dispute(dispute);
+
+            Ok(())
+        }
+
+        /// Assert if the verdict is for one of the parties, but no votes were counted.
+        fn assert_verdict_has_votes(dispute: &Dispute, verdict: &Verdict) -> Result<()> {
+            if *verdict != Verdict::None && dispute.number_of_votes() == 0 {
+                return Err(BrightDisputesError::MajorityOfVotesNotReached);
+            }
+            Ok(())
+        }
+
+        /// End the dispute or start a new dispute round, according to the verdict.
+        fn apply_verdict(
+            &mut self,
+            dispute: &mut Dispute,
+            verdict: Verdict,
+            jurors_banned: AccountsVec,
+        ) -> Result<()> {
+            Self::assert_verdict_has_votes(dispute, &verdict)?;
+
+            let dispute_id = dispute.id();
             match verdict {
                 Verdict::Positive => {
-                    dispute.end_dispute(Some(DisputeResult::Owner), jurors_banned)?;
-                    self.distribute_deposit(&mut dispute)?;
+                    dispute.end_dispute(Some(DisputeResult::Owner), jurors_banned.clone())?;
+                    self.update_verdict_stats(dispute, &jurors_banned);
+                    self.emit_dispute_result(dispute);
+                    self.distribute_deposit(dispute)?;
                 }
                 Verdict::Negative => {
-                    dispute.end_dispute(Some(DisputeResult::Defendant), jurors_banned)?;
-                    self.distribute_deposit(&mut dispute)?;
+                    dispute.end_dispute(Some(DisputeResult::Defendant), jurors_banned.clone())?;
+                    self.update_verdict_stats(dispute, &jurors_banned);
+                    self.emit_dispute_result(dispute);
+                    self.distribute_deposit(dispute)?;
                 }
                 Verdict::None => {
                     // Check if juries votes
@@ -392,39 +1014,96 @@ pub mod bright_disputes {
                         let juror = self.get_juror_or_assert(juror_id)?;
                         if juror.is_requested_for_action(dispute_id) {
                             dispute.move_to_banned(juror_id)?;
+                            self.update_juror_stats(juror_id, |stats| {
+                                stats.increment_missed_deadlines()
+                            });
+                            self.on_juror_banned(dispute_id, juror_id);
                         }
                     }
 
                     // Dispute round ended, but the majority of votes is not reached.
-                    match dispute.next_dispute_round(self.env().block_timestamp()) {
+                    // In the private voting, juries are limited by the verdict proof capacity.
+                    let max_juries = match dispute.voting_mode() {
+                        VotingMode::Private => self.verifier().max_votes(),
+                        VotingMode::CommitReveal => None,
+                    };
+                    match dispute.next_dispute_round(self.env().block_timestamp(), max_juries) {
+                        Ok(()) => self.emit_new_dispute_round(dispute),
                         Err(BrightDisputesError::DisputeRoundLimitReached) => {
-                            dispute.end_dispute(None, jurors_banned)?;
-                            self.distribute_deposit(&mut dispute)?;
+                            dispute.end_dispute(None, jurors_banned.clone())?;
+                            self.update_verdict_stats(dispute, &jurors_banned);
+                            self.emit_dispute_result(dispute);
+                            self.distribute_deposit(dispute)?;
                         }
                         _ => (),
                     }
                 }
             };
-            self.update_dispute(dispute);
-
             Ok(())
         }
 
-        /// Unregister juror from the active juries pool.
-        #[ink(message)]
-        pub fn process_dispute_round(&mut self, dispute_id: DisputeId) -> Result<()> {
-            let timestamp = self.env().block_timestamp();
-            let mut dispute = self.get_dispute_or_assert(dispute_id)?;
-            if let Err(e) = dispute.process_dispute_round(self, timestamp) {
-                match e {
+        /// Count the revealed votes on-chain (commit-reveal voting mode).
+        /// Juries who voted against the majority are banned, abstentions are
+        /// not counted.
+        fn count_revealed_votes(&mut self, dispute: &mut Dispute) -> Result<()> {
+            let revealed_votes: Vec<(AccountId, u8)> = dispute
+                .revealed_votes()
+                .into_iter()
+                .filter(|(_, vote)| *vote != Vote::ABSTAIN)
+                .collect();
+            let number_of_votes = revealed_votes.len() as u32;
+            let majority_threshold = dispute.majority_threshold();
+            let votes_minimum = majority_threshold.votes_minimum(number_of_votes);
+            let votes_maximum = majority_threshold.votes_maximum(number_of_votes);
+            let sum_votes = revealed_votes
+                .iter()
+                .map(|(_, vote)| *vote as u32)
+                .sum::<u32>();
+
+            let (verdict, against) = if number_of_votes == 0 {
+                (Verdict::None, None)
+            } else if sum_votes >= votes_minimum {
+                (Verdict::Positive, Some(0))
+            } else if sum_votes <= votes_maximum {
+                (Verdict::Negative, Some(1))
+            } else {
+                (Verdict::None, None)
+            };
+            let jurors_banned: AccountsVec = revealed_votes
+                .iter()
+                .filter(|(_, vote)| Some(*vote) == against)
+                .map(|(juror_id, _)| *juror_id)
+                .collect();
+
+            self.apply_verdict(dispute, verdict, jurors_banned)
+        }
+
+        /// Unregister juror from the active juries pool.
+        #[ink(message)]
+        pub fn process_dispute_round(&mut self, dispute_id: DisputeId) -> Result<()> {
+            let timestamp = self.env().block_timestamp();
+            let mut dispute = self.get_dispute_or_assert(dispute_id)?;
+            if let Err(e) = dispute.process_dispute_round(self, timestamp) {
+                match e {
                     BrightDisputesError::DisputeRoundDeadlineReached => {
+                        if let Some(round) = dispute.dispute_round() {
+                            self.env().emit_event(RoundDeadlineReached {
+                                dispute_id,
+                                state: round.state(),
+                            });
+                        }
+
                         // Check if judge counted the votes.
                         if let Some(judge_id) = dispute.judge() {
                             let judge = self.get_juror_or_assert(judge_id)?;
                             if judge.is_requested_for_action(dispute_id) {
                                 dispute.move_to_banned(judge_id)?;
+                                self.update_juror_stats(judge_id, |stats| {
+                                    stats.increment_missed_deadlines()
+                                });
+                                self.on_juror_banned(dispute_id, judge_id);
                             } else {
-                                self.add_to_juries_pool(judge_id)?;
+                                self.release_juror(judge_id)?;
                             }
                         }
 
@@ -433,13 +1112,18 @@ pub mod bright_disputes {
                             let juror = self.get_juror_or_assert(juror_id)?;
                             if juror.is_requested_for_action(dispute_id) {
                                 dispute.move_to_banned(juror_id)?;
+                                self.update_juror_stats(juror_id, |stats| {
+                                    stats.increment_missed_deadlines()
+                                });
+                                self.on_juror_banned(dispute_id, juror_id);
                             } else {
-                                self.add_to_juries_pool(juror_id)?;
+                                self.release_juror(juror_id)?;
                             }
                         }
 
                         // Whenever deadline is reached, we start a new dispute round.
                         dispute.on_dispute_round_deadline(timestamp)?;
+                        self.emit_new_dispute_round(&dispute);
                     }
 
                     _ => {
@@ -447,28 +1131,92 @@ pub mod bright_disputes {
                         return Err(e);
                     }
                 }
+            } else if dispute.voting_mode() == VotingMode::CommitReveal
+                && dispute
+                    .dispute_round()
+                    .map_or(false, |round| round.state() == RoundState::CountingTheVotes)
+            {
+                self.count_revealed_votes(&mut dispute)?;
             }
             self.update_dispute(dispute);
             Ok(())
         }
 
-        /// Register a verification key.
+        /// Propose the settlement of the dispute (call by owner or defendant).
+        /// `owner_share` is the percentage of the parties funds, which goes to
+        /// the owner, the rest goes to the defendant.
         #[ink(message)]
-        pub fn register_vk(&mut self, relation: Relation, vk: Vec<u8>) -> Result<()> {
-            let identifier = match relation {
-                Relation::Vote => VOTE_VK_IDENTIFIER,
-                Relation::VerdictNegative => VERDICT_NEGATIVE_VK_IDENTIFIER,
-                Relation::VerdictNone => VERDICT_NONE_VK_IDENTIFIER,
-                Relation::VerdictPositive => VERDICT_POSITIVE_VK_IDENTIFIER,
-            };
+        pub fn propose_settlement(&mut self, dispute_id: DisputeId, owner_share: u8) -> Result<()> {
+            let mut dispute = self.get_dispute_or_assert(dispute_id)?;
+            dispute.propose_settlement(owner_share)?;
+            self.update_dispute(dispute);
+
+            self.env().emit_event(SettlementProposed {
+                dispute_id,
+                proposed_by: ink::env::caller::<ink::env::DefaultEnvironment>(),
+                owner_share,
+            });
+            Ok(())
+        }
+
+        /// Accept the settlement proposed by the other party. Dispute is closed,
+        /// juries and judge are released back to the pool. Confirmed juries and
+        /// judge get back their escrow and share a fee taken from the parties
+        /// funds, the rest is split between the parties. If the defendant hasn't
+        /// confirmed the dispute, only the owner has deposited and gets it back.
+        #[ink(message)]
+        pub fn accept_settlement(&mut self, dispute_id: DisputeId) -> Result<()> {
+            let mut dispute = self.get_dispute_or_assert(dispute_id)?;
+            let settlement = dispute.accept_settlement()?;
+
+            // Release juries and judge.
+            let mut confirmed: Vec<AccountId> = Vec::new();
+            for juror_id in dispute.judge().into_iter().chain(dispute.juries()) {
+                let juror = self.get_juror_or_assert(juror_id)?;
+                if juror.is_confirmed(dispute_id) {
+                    confirmed.push(juror_id);
+                }
+                if !self.juries_pool_indexes.contains(juror_id) {
+                    self.release_juror(juror_id)?;
+                }
+            }
 
-            self.env()
-                .extension()
-                .store_key(self.env().caller(), identifier, vk)?;
+            // Split the deposit.
+            let escrow = dispute.escrow();
+            let mut parties_funds = dispute
+                .deposit()
+                .saturating_sub(escrow * confirmed.len() as Balance);
+            if !confirmed.is_empty() {
+                let juror_fee = parties_funds * Self::SETTLEMENT_JURIES_FEE_PERCENT
+                    / 100
+                    / confirmed.len() as Balance;
+                for juror_id in confirmed.iter() {
+                    self.pay_out(dispute_id, *juror_id, escrow + juror_fee)?;
+                }
+                parties_funds -= juror_fee * confirmed.len() as Balance;
+            }
+            let owner_funds = if dispute.has_defendant_confirmed_dispute() {
+                parties_funds * settlement.owner_share() as Balance / 100
+            } else {
+                parties_funds
+            };
+            self.pay_out(dispute_id, dispute.owner(), owner_funds)?;
+            self.pay_out(dispute_id, dispute.defendant(), parties_funds - owner_funds)?;
+            self.update_dispute(dispute);
 
+            self.env().emit_event(DisputeSettled {
+                dispute_id,
+                owner_share: settlement.owner_share(),
+            });
             Ok(())
         }
 
+        /// Register a verification key.
+        #[ink(message)]
+        pub fn register_vk(&mut self, relation: Relation, vk: Vec<u8>) -> Result<()> {
+            self.verifier().store_key(self.env().caller(), relation, vk)
+        }
+
         /// Get Juror/Judge public key if he is assigned to the dispute.
         #[ink(message)]
         pub fn juror_public_key(
@@ -490,6 +1238,70 @@ pub mod bright_disputes {
             Err(BrightDisputesError::JurorHasNotConfirmedDispute)
         }
 
+        /// Check if the Juror/Judge has confirmed participation in the dispute. Public key of
+        /// the juror can be left from the previous dispute, so it is not a confirmation.
+        #[ink(message)]
+        pub fn has_confirmed_participation(
+            &self,
+            dispute_id: DisputeId,
+            juror_id: AccountId,
+        ) -> Result<bool> {
+            self.get_dispute_or_assert(dispute_id)?;
+            Ok(self
+                .get_juror_or_assert(juror_id)
+                .map_or(false, |juror| juror.is_confirmed(dispute_id)))
+        }
+
+        /// Attach evidence encrypted for the jurors / judge of the dispute.
+        /// Only the owner or the defendant can do it, while the dispute is
+        /// created or running. Evidence can be attached only for accounts
+        /// which confirmed participation in the dispute and stored their
+        /// public key. Size of the evidence and number of evidences each
+        /// party attaches for the juror are limited.
+        #[ink(message)]
+        pub fn submit_evidence(
+            &mut self,
+            dispute_id: DisputeId,
+            public_key: PublicKey,
+            evidences: Vec<(AccountId, Vec<u8>)>,
+        ) -> Result<()> {
+            let dispute = self.get_dispute_or_assert(dispute_id)?;
+            dispute.assert_owner_or_defendant_call()?;
+            dispute.assert_can_submit_evidence()?;
+
+            let caller = ink::env::caller::<ink::env::DefaultEnvironment>();
+            for (juror_id, data) in evidences {
+                self.juror_public_key(dispute_id, juror_id)?;
+                if data.len() > Self::EVIDENCE_MAX_SIZE {
+                    return Err(BrightDisputesError::EvidenceTooLarge);
+                }
+
+                let mut juror_evidences = self
+                    .evidences
+                    .get((dispute_id, juror_id))
+                    .unwrap_or_default();
+                let submitted = juror_evidences
+                    .iter()
+                    .filter(|evidence| evidence.author() == caller)
+                    .count();
+                if submitted >= Self::EVIDENCES_PER_PARTY_LIMIT {
+                    return Err(BrightDisputesError::EvidenceLimitReached);
+                }
+                juror_evidences.push(Evidence::create(caller, public_key.clone(), data));
+                self.evidences
+                    .insert((dispute_id, juror_id), &juror_evidences);
+            }
+            Ok(())
+        }
+
+        /// Get evidence encrypted for the juror / judge of the dispute.
+        #[ink(message)]
+        pub fn get_evidence(&self, dispute_id: DisputeId, juror_id: AccountId) -> Vec<Evidence> {
+            self.evidences
+                .get((dispute_id, juror_id))
+                .unwrap_or_default()
+        }
+
         fn update_dispute(&mut self, dispute: Dispute) {
             self.disputes.insert(dispute.id(), &dispute);
         }
@@ -508,46 +1320,86 @@ pub mod bright_disputes {
                 .ok_or(BrightDisputesError::DisputeNotExist)
         }
 
+        /// Remove juror from the pool, by moving the last juror of the pool in its place.
         fn remove_juror_from_pool_or_assert(&mut self, juror_id: AccountId) -> Result<()> {
-            if let Some(index) = self.juries_pool.iter().position(|&j| j == juror_id) {
-                self.juries_pool.remove(index);
-                return Ok(());
+            let index = self
+                .juries_pool_indexes
+                .get(juror_id)
+                .ok_or(BrightDisputesError::NotRegisteredAsJuror)?;
+
+            let last_index = self.juries_pool_len - 1;
+            if index != last_index {
+                let last_juror_id = self
+                    .juries_pool
+                    .get(last_index)
+                    .ok_or(BrightDisputesError::JurorNotExist)?;
+                self.juries_pool.insert(index, &last_juror_id);
+                self.juries_pool_indexes.insert(last_juror_id, &index);
             }
-            return Err(BrightDisputesError::NotRegisteredAsJuror);
+
+            self.juries_pool.remove(last_index);
+            self.juries_pool_indexes.remove(juror_id);
+            self.juries_pool_len = last_index;
+            Ok(())
         }
 
         fn add_to_juries_pool(&mut self, juror_id: AccountId) -> Result<()> {
             self.assert_juror_not_in_pool(juror_id)?;
+            if self.get_juror_suspension(juror_id).is_some() {
+                return Err(BrightDisputesError::JurorSuspended);
+            }
 
             let juror = Juror::create(juror_id);
-            self.juries_pool.push(juror.id());
+            self.juries_pool.insert(self.juries_pool_len, &juror.id());
+            self.juries_pool_indexes
+                .insert(juror.id(), &self.juries_pool_len);
+            self.juries_pool_len += 1;
             self.update_juror(juror);
             Ok(())
         }
 
+        /// Release the juror from the dispute. Juror is added back to the pool,
+        /// unless he is suspended.
+        fn release_juror(&mut self, juror_id: AccountId) -> Result<()> {
+            if self.get_juror_suspension(juror_id).is_some() {
+                self.update_juror(Juror::create(juror_id));
+                return Ok(());
+            }
+            self.add_to_juries_pool(juror_id)
+        }
+
+        /// Pick juries from the consecutive places of the pool, starting from the
+        /// random one. Only excepted juries can be skipped, so we never need to
+        /// check more than `number_of_juries + except_juries.len()` places.
         fn get_random_juries_from_pool(
             &self,
             except_juries: &Vec<AccountId>,
             number_of_juries: u8,
             seed: u64,
         ) -> Result<Vec<AccountId>> {
-            let filtered_pool: Vec<AccountId> = self
-                .juries_pool
-                .iter()
-                .filter(|juror_id| !except_juries.contains(&juror_id))
-                .map(|&juror_id| juror_id)
-                .collect();
-
-            let pool_len: u64 = filtered_pool.len().try_into().unwrap();
-            if filtered_pool.len() < number_of_juries.into() {
-                return Err(BrightDisputesError::JuriesPoolIsToSmall);
-            }
+            let pool_len = self.juries_pool_len as u64;
+            let places_to_check =
+                pool_len.min(number_of_juries as u64 + except_juries.len() as u64);
 
             let mut juries: Vec<AccountId> = Vec::new();
             let random_start = self.pseudo_random(seed);
-            for i in 0..number_of_juries {
-                let index: usize = ((i as u64 + random_start) % pool_len).try_into().unwrap();
-                juries.push(filtered_pool[index]);
+            for i in 0..places_to_check {
+                if juries.len() == number_of_juries as usize {
+                    break;
+                }
+
+                let index = ((i + random_start) % pool_len) as u32;
+                let juror_id = self
+                    .juries_pool
+                    .get(index)
+                    .ok_or(BrightDisputesError::JurorNotExist)?;
+                if !except_juries.contains(&juror_id) {
+                    juries.push(juror_id);
+                }
+            }
+
+            if juries.len() < number_of_juries as usize {
+                return Err(BrightDisputesError::JuriesPoolIsToSmall);
             }
             Ok(juries)
         }
@@ -560,18 +1412,26 @@ pub mod bright_disputes {
             // Close dispute
             dispute.close_dispute()?;
 
-            let mut accounts: Vec<AccountId> = Vec::new();
+            // Accounts with their percentage of the deposit share.
+            let mut accounts: Vec<(AccountId, Balance)> = Vec::new();
 
             // Add judge
             if let Some(judge_id) = dispute.judge() {
-                accounts.push(judge_id.clone());
-                self.add_to_juries_pool(judge_id)?;
+                accounts.push((judge_id.clone(), 100));
+                self.release_juror(judge_id)?;
+                self.update_juror_stats(judge_id, |stats| stats.increment_cases_served());
             }
 
-            // Add juries, who were not banned.
+            // Add juries, who were not banned. Juries who abstained get a reduced fee.
+            let abstained_juries = dispute.abstained_juries();
             for juror_id in dispute.juries() {
-                accounts.push(juror_id.clone());
-                self.add_to_juries_pool(juror_id)?;
+                if abstained_juries.contains(&juror_id) {
+                    accounts.push((juror_id.clone(), Self::ABSTAIN_FEE_PERCENT));
+                } else {
+                    accounts.push((juror_id.clone(), 100));
+                }
+                self.release_juror(juror_id)?;
+                self.update_juror_stats(juror_id, |stats| stats.increment_cases_served());
             }
 
             // If the dispute reaches the maximum number of rounds,
@@ -579,26 +1439,233 @@ pub mod bright_disputes {
             // deposit to the Owner and Defendant as well.
             if dispute.get_dispute_result().is_none() {
                 // Add owner
-                accounts.push(dispute.owner());
+                accounts.push((dispute.owner(), 100));
 
                 // Add defendant, only if he confirmed dispute
                 if dispute.has_defendant_confirmed_dispute() {
-                    accounts.push(dispute.defendant());
+                    accounts.push((dispute.defendant(), 100));
                 }
             }
 
             // Split deposit and transfer founds.
-            let founds = dispute.deposit() / accounts.len() as Balance;
-            for account in accounts {
-                self.env().transfer(account, founds)?;
+            let shares: Balance = accounts.iter().map(|(_, share)| share).sum();
+            for (account_id, share) in accounts {
+                let founds = dispute.deposit() * share / shares;
+                self.pay_out(dispute.id(), account_id, founds)?;
+            }
+            Ok(())
+        }
+
+        fn pay_out(
+            &mut self,
+            dispute_id: DisputeId,
+            account_id: AccountId,
+            amount: Balance,
+        ) -> Result<()> {
+            if amount == 0 {
+                return Ok(());
             }
+            self.env().transfer(account_id, amount)?;
+            self.env().emit_event(DepositPaid {
+                dispute_id,
+                account_id,
+                amount,
+            });
             Ok(())
         }
 
-        fn serialize<T: CanonicalSerialize + ?Sized>(t: &T) -> Vec<u8> {
-            let mut bytes = vec![0; t.serialized_size()];
-            t.serialize(&mut bytes[..]).expect("Failed to serialize");
-            bytes.to_vec()
+        fn add_account_dispute(&mut self, account_id: AccountId, dispute_id: DisputeId) {
+            let mut disputes_ids = self.get_account_disputes(account_id);
+            if !disputes_ids.contains(&dispute_id) {
+                disputes_ids.push(dispute_id);
+                self.account_disputes.insert(account_id, &disputes_ids);
+            }
+        }
+
+        fn remove_account_dispute(&mut self, account_id: AccountId, dispute_id: DisputeId) {
+            let mut disputes_ids = self.get_account_disputes(account_id);
+            disputes_ids.retain(|id| *id != dispute_id);
+            if disputes_ids.is_empty() {
+                self.account_disputes.remove(account_id);
+            } else {
+                self.account_disputes.insert(account_id, &disputes_ids);
+            }
+        }
+
+        fn update_juror_stats<F: FnOnce(&mut JurorStats)>(
+            &mut self,
+            juror_id: AccountId,
+            update: F,
+        ) {
+            let mut stats = self.get_juror_stats(juror_id);
+            update(&mut stats);
+            self.juries_stats.insert(juror_id, &stats);
+        }
+
+        /// Count juror bans, suspend the juror whenever the limit of bans is reached.
+        fn on_juror_banned(&mut self, dispute_id: DisputeId, juror_id: AccountId) {
+            self.update_juror_stats(juror_id, |stats| stats.increment_bans());
+            self.env().emit_event(JurorBanned {
+                dispute_id,
+                juror_id,
+            });
+
+            let bans = self.get_juror_stats(juror_id).bans();
+            if self.suspension_bans_limit == 0 || bans % self.suspension_bans_limit != 0 {
+                return;
+            }
+
+            let suspended_until = match self.suspension_time {
+                Some(time) => self.env().block_timestamp().saturating_add(time),
+                None => Timestamp::MAX,
+            };
+            self.suspensions.insert(juror_id, &suspended_until);
+
+            self.env().emit_event(JurorSuspended {
+                juror_id,
+                suspended_until,
+            });
+        }
+
+        /// Juries who were banned by the verdict voted against the majority,
+        /// all remaining juries, except the ones who abstained, voted with the majority.
+        fn update_verdict_stats(&mut self, dispute: &Dispute, jurors_banned: &AccountsVec) {
+            for juror_id in jurors_banned {
+                self.on_juror_banned(dispute.id(), *juror_id);
+            }
+            if dispute.get_dispute_result().is_some() {
+                let abstained_juries = dispute.abstained_juries();
+                for juror_id in dispute.juries() {
+                    if abstained_juries.contains(&juror_id) {
+                        continue;
+                    }
+                    self.update_juror_stats(juror_id, |stats| {
+                        stats.increment_votes_with_majority()
+                    });
+                }
+            }
+        }
+
+        fn pending_action(
+            &self,
+            dispute: &Dispute,
+            account_id: AccountId,
+            now: Timestamp,
+        ) -> Option<ActionKind> {
+            if dispute.state() == DisputeState::Created {
+                if dispute.defendant() == account_id {
+                    return Some(ActionKind::ConfirmDefendant);
+                } else if dispute.owner() == account_id {
+                    let rules = self.default_judgment_rules.as_ref()?;
+                    if rules.assert_allowed(dispute, now).is_ok() {
+                        return Some(ActionKind::RequestDefaultJudgment);
+                    }
+                }
+                return None;
+            } else if dispute.state() != DisputeState::Running {
+                return None;
+            }
+
+            let round = dispute.dispute_round()?;
+            if dispute.owner() == account_id {
+                if self.can_process_dispute_round(dispute, &round, now) {
+                    return Some(ActionKind::ProcessDisputeRound);
+                }
+                return None;
+            }
+
+            let is_judge = dispute.judge() == Some(account_id);
+            if !is_judge && !dispute.juries().contains(&account_id) {
+                return None;
+            }
+            let juror = self.juries.get(account_id)?;
+            match round.state() {
+                RoundState::PickingJuriesAndJudge if !juror.is_confirmed(dispute.id()) => {
+                    if is_judge {
+                        Some(ActionKind::ConfirmJudge)
+                    } else {
+                        Some(ActionKind::ConfirmJuror)
+                    }
+                }
+                RoundState::Voting | RoundState::CountingTheVotes
+                    if juror.is_requested_for_action(dispute.id()) =>
+                {
+                    if is_judge {
+                        Some(ActionKind::CountTheVotes)
+                    } else {
+                        Some(ActionKind::Vote)
+                    }
+                }
+                RoundState::Revealing if juror.is_requested_for_action(dispute.id()) => {
+                    Some(ActionKind::RevealVote)
+                }
+                _ => None,
+            }
+        }
+
+        /// Check if owner is able to switch the dispute round to the next state.
+        fn can_process_dispute_round(
+            &self,
+            dispute: &Dispute,
+            round: &DisputeRound,
+            now: Timestamp,
+        ) -> bool {
+            if now >= round.state_deadline() {
+                return true;
+            }
+            match round.state() {
+                RoundState::AssignJuriesAndJudge => true,
+                RoundState::PickingJuriesAndJudge => {
+                    dispute.judge().is_some()
+                        && !dispute.juries().is_empty()
+                        && dispute
+                            .judge()
+                            .into_iter()
+                            .chain(dispute.juries())
+                            .all(|id| {
+                                self.juries
+                                    .get(id)
+                                    .map_or(false, |juror| juror.is_confirmed(dispute.id()))
+                            })
+                }
+                RoundState::Voting => {
+                    !dispute.votes().is_empty() && dispute.get_not_voted_juries().is_empty()
+                }
+                RoundState::Revealing => dispute.get_not_revealed_juries().is_empty(),
+                RoundState::CountingTheVotes => false,
+            }
+        }
+
+        fn emit_dispute_result(&self, dispute: &Dispute) {
+            self.env().emit_event(DisputeResultEvent {
+                id: dispute.id(),
+                result: dispute.get_dispute_result(),
+            });
+        }
+
+        fn emit_new_dispute_round(&self, dispute: &Dispute) {
+            if let Some(round) = dispute.dispute_round() {
+                self.env().emit_event(RoundStateChanged {
+                    dispute_id: dispute.id(),
+                    state: round.state(),
+                    deadline: round.state_deadline(),
+                });
+            }
+        }
+
+        #[cfg(feature = "baby-liminal")]
+        fn verifier(&self) -> impl Verifier {
+            BabyLiminalVerifier(self.env().extension())
+        }
+
+        #[cfg(all(feature = "mock-verifier", not(feature = "baby-liminal")))]
+        fn verifier(&self) -> impl Verifier {
+            MockVerifier
+        }
+
+        #[cfg(not(any(feature = "baby-liminal", feature = "mock-verifier")))]
+        fn verifier(&self) -> impl Verifier {
+            NoVerifier
         }
 
         fn pseudo_random(&self, seed: u64) -> u64 {
@@ -607,10 +1674,8 @@ pub mod bright_disputes {
         }
 
         fn assert_juror_not_in_pool(&self, juror_id: AccountId) -> Result<()> {
-            for j in &self.juries_pool {
-                if *j == juror_id {
-                    return Err(BrightDisputesError::JurorAlreadyRegistered);
-                }
+            if self.juries_pool_indexes.contains(juror_id) {
+                return Err(BrightDisputesError::JurorAlreadyRegistered);
             }
             return Ok(());
         }
@@ -626,6 +1691,7 @@ pub mod bright_disputes {
 
     #[cfg(test)]
     mod tests {
+        #[cfg(feature = "baby-liminal")]
         mod mock {
             use baby_liminal_extension::{
                 BabyLiminalError, BabyLiminalExtension, VerificationKeyIdentifier,
@@ -655,12 +1721,35 @@ pub mod bright_disputes {
         }
 
         use ink::env::{
-            test::{set_caller, set_value_transferred},
+            test::{set_block_timestamp, set_caller, set_value_transferred},
             DefaultEnvironment,
         };
 
         use super::*;
 
+        /// Register the mocked chain extension, with the mock verifier all proofs are accepted anyway.
+        #[cfg(any(feature = "baby-liminal", feature = "mock-verifier"))]
+        fn register_verifier() {
+            #[cfg(feature = "baby-liminal")]
+            mock::register_chain_extensions(());
+        }
+
+        /// Hash of the recorded votes, which the verdict has to be issued for.
+        #[cfg(any(feature = "baby-liminal", feature = "mock-verifier"))]
+        fn recorded_votes_hash(
+            bright_disputes: &BrightDisputes,
+            dispute_id: DisputeId,
+        ) -> VoteHash {
+            let dispute = bright_disputes
+                .get_dispute(dispute_id)
+                .expect("Failed to get dispute!");
+            let votes: Vec<VoteHash> = dispute.votes().iter().map(|vote| vote.vote()).collect();
+            bright_disputes
+                .verifier()
+                .verdict_votes_hash(&votes)
+                .unwrap_or_default()
+        }
+
         fn create_test_bright_dispute_with_running_dispute() -> BrightDisputes {
             let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
             let mut bright_disputes = BrightDisputes::new();
@@ -699,7 +1788,7 @@ pub mod bright_disputes {
                 .register_as_an_active_juror()
                 .expect("Failed to register django as a juror!");
 
-            assert_eq!(bright_disputes.juries_pool.len(), 4);
+            assert_eq!(bright_disputes.juries_pool_len, 4);
             assert!(bright_disputes.juries.contains(accounts.charlie));
             assert!(bright_disputes.juries.contains(accounts.eve));
             assert!(bright_disputes.juries.contains(accounts.frank));
@@ -753,6 +1842,46 @@ pub mod bright_disputes {
             assert_eq!(result, Ok(2));
         }
 
+        /// Test if we can create a dispute with a custom majority threshold.
+        #[ink::test]
+        fn create_dispute_with_majority_threshold() {
+            let mut bright_disputes = BrightDisputes::new();
+
+            let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            set_value_transferred::<DefaultEnvironment>(10);
+
+            // Failed, threshold has to be a part of the votes.
+            for (numerator, denominator) in [(0, 2), (3, 2), (1, 0)] {
+                let result = bright_disputes.create_dispute_with_voting_mode(
+                    "".into(),
+                    accounts.bob,
+                    10,
+                    VotingMode::CommitReveal,
+                    Some(MajorityThreshold::new(numerator, denominator)),
+                );
+                assert_eq!(result, Err(BrightDisputesError::InvalidMajorityThreshold));
+            }
+
+            let dispute_id = bright_disputes
+                .create_dispute_with_voting_mode(
+                    "".into(),
+                    accounts.bob,
+                    10,
+                    VotingMode::CommitReveal,
+                    Some(MajorityThreshold::new(1, 2)),
+                )
+                .expect("Failed to create a dispute!");
+            let dispute = bright_disputes
+                .get_dispute(dispute_id)
+                .expect("Failed to get dispute!");
+            assert_eq!(dispute.majority_threshold(), MajorityThreshold::new(1, 2));
+
+            // Failed, verdict thresholds are computed only in the private voting mode.
+            let result = bright_disputes.get_verdict_thresholds(dispute_id);
+            assert_eq!(result, Err(BrightDisputesError::InvalidVotingMode));
+        }
+
         /// Test if we can get single disputes.
         #[ink::test]
         fn get_single_dispute() {
@@ -802,58 +1931,220 @@ pub mod bright_disputes {
             );
         }
 
-        // Test if we can remove a single dispute.
+        /// Test paginated and filtered disputes queries.
         #[ink::test]
-        fn remove_dispute() {
+        fn get_disputes() {
             let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
             let mut bright_disputes = BrightDisputes::new();
 
-            let result = bright_disputes.remove_dispute(1);
-            assert_eq!(result, Err(BrightDisputesError::DisputeNotExist));
-
-            // Create dispute
             set_value_transferred::<DefaultEnvironment>(10);
+
+            // Alice creates two disputes, bob creates one.
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            for defendant_id in [accounts.bob, accounts.charlie] {
+                bright_disputes
+                    .create_dispute("https://brightinventions.pl/".into(), defendant_id, 10)
+                    .expect("Failed to create a dispute!");
+            }
+            set_caller::<DefaultEnvironment>(accounts.bob);
             bright_disputes
-                .create_dispute("https://brightinventions.pl".into(), accounts.bob, 10)
+                .create_dispute("https://brightinventions.pl/".into(), accounts.alice, 10)
                 .expect("Failed to create a dispute!");
-            let result = bright_disputes.remove_dispute(1);
-            assert_eq!(result, Ok(()));
-
-            let result = bright_disputes.remove_dispute(1);
-            assert_eq!(result, Err(BrightDisputesError::DisputeNotExist));
-
-            // Failed to remove dispute in "Running" state.
-            let mut bright_disputes = create_test_bright_dispute_with_running_dispute();
-            let result = bright_disputes.remove_dispute(1);
-            assert_eq!(result, Err(BrightDisputesError::InvalidDisputeState));
+            bright_disputes
+                .confirm_defendant(1, "".into())
+                .expect("Failed to confirm defendant a dispute!");
 
-            // Failed to remove dispute in "Ended" state.
-            let mut dispute = bright_disputes
-                .get_dispute(1)
-                .expect("Failed to get dispute!");
-            dispute
-                .end_dispute(Some(DisputeResult::Owner), vec![])
-                .expect("Failed to end dispute!");
-            bright_disputes.update_dispute(dispute.clone());
-            let result = bright_disputes.remove_dispute(1);
-            assert_eq!(result, Err(BrightDisputesError::InvalidDisputeState));
+            let ids = |disputes: Vec<Dispute>| -> Vec<DisputeId> {
+                disputes.iter().map(|dispute| dispute.id()).collect()
+            };
 
-            // Success
-            dispute.close_dispute().expect("Failed to close dispute!");
-            bright_disputes.update_dispute(dispute);
-            let result = bright_disputes.remove_dispute(1);
-            assert_eq!(result, Ok(()));
-        }
+            // No filter, pagination.
+            let all = DisputeFilter::default();
+            assert_eq!(
+                ids(bright_disputes.get_disputes(0, 10, all.clone())),
+                vec![1, 2, 3]
+            );
+            assert_eq!(
+                ids(bright_disputes.get_disputes(1, 1, all.clone())),
+                vec![2]
+            );
+            assert_eq!(ids(bright_disputes.get_disputes(3, 10, all)), vec![]);
 
-        /// Test confirmation of the defendant
-        #[ink::test]
-        fn confirm_defendant() {
-            let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
-            set_caller::<DefaultEnvironment>(accounts.alice);
+            // Filter by state.
+            let running = DisputeFilter {
+                state: Some(DisputeState::Running),
+                ..Default::default()
+            };
+            assert_eq!(ids(bright_disputes.get_disputes(0, 10, running)), vec![1]);
 
-            let mut bright_disputes = BrightDisputes::new();
+            // Filter by owner and defendant.
+            let alice_owner = DisputeFilter {
+                owner: Some(accounts.alice),
+                ..Default::default()
+            };
+            assert_eq!(
+                ids(bright_disputes.get_disputes(0, 10, alice_owner)),
+                vec![1, 2]
+            );
+            let alice_defendant = DisputeFilter {
+                defendant: Some(accounts.alice),
+                ..Default::default()
+            };
+            assert_eq!(
+                ids(bright_disputes.get_disputes(0, 10, alice_defendant)),
+                vec![3]
+            );
+            let bob_created = DisputeFilter {
+                state: Some(DisputeState::Created),
+                defendant: Some(accounts.bob),
+                ..Default::default()
+            };
+            assert_eq!(
+                ids(bright_disputes.get_disputes(0, 10, bob_created)),
+                vec![]
+            );
 
-            let defendant_link = "https://brightinventions.pl/";
+            // Per account index.
+            assert_eq!(
+                bright_disputes.get_account_disputes(accounts.alice),
+                vec![1, 2, 3]
+            );
+            assert_eq!(
+                bright_disputes.get_account_disputes(accounts.bob),
+                vec![1, 3]
+            );
+            assert_eq!(bright_disputes.get_account_disputes(accounts.eve), vec![]);
+
+            // Filter by multiple accounts, all of them need to match.
+            let alice_owner_bob_defendant = DisputeFilter {
+                owner: Some(accounts.alice),
+                defendant: Some(accounts.bob),
+                ..Default::default()
+            };
+            assert_eq!(
+                ids(bright_disputes.get_disputes(0, 10, alice_owner_bob_defendant)),
+                vec![1]
+            );
+            let charlie_owner_alice_defendant = DisputeFilter {
+                owner: Some(accounts.charlie),
+                defendant: Some(accounts.alice),
+                ..Default::default()
+            };
+            assert_eq!(
+                ids(bright_disputes.get_disputes(0, 10, charlie_owner_alice_defendant)),
+                vec![]
+            );
+
+            // Removed disputes are removed from the index.
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            bright_disputes
+                .remove_dispute(2)
+                .expect("Failed to remove a dispute!");
+            let alice_owner = DisputeFilter {
+                owner: Some(accounts.alice),
+                ..Default::default()
+            };
+            assert_eq!(
+                ids(bright_disputes.get_disputes(0, 10, alice_owner)),
+                vec![1]
+            );
+            assert_eq!(
+                bright_disputes.get_account_disputes(accounts.alice),
+                vec![1, 3]
+            );
+            assert_eq!(
+                bright_disputes.get_account_disputes(accounts.charlie),
+                vec![]
+            );
+        }
+
+        /// Test if assigned juries and judge are indexed with the dispute.
+        #[ink::test]
+        fn get_disputes_by_juror() {
+            let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+
+            let mut bright_disputes = create_test_bright_dispute_with_running_dispute();
+            let dispute_id = 1;
+            register_valid_juries(&mut bright_disputes);
+
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            bright_disputes
+                .process_dispute_round(dispute_id)
+                .expect("Failed to process dispute round!");
+
+            let dispute = bright_disputes
+                .get_dispute(dispute_id)
+                .expect("Failed to get dispute!");
+            assert!(!dispute.juries().is_empty());
+            for juror_id in dispute.juries() {
+                assert_eq!(
+                    bright_disputes.get_account_disputes(juror_id),
+                    vec![dispute_id]
+                );
+                let filter = DisputeFilter {
+                    juror: Some(juror_id),
+                    ..Default::default()
+                };
+                assert_eq!(
+                    bright_disputes.get_disputes(0, 10, filter),
+                    vec![dispute.clone()]
+                );
+            }
+        }
+
+        // Test if we can remove a single dispute.
+        #[ink::test]
+        fn remove_dispute() {
+            let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+            let mut bright_disputes = BrightDisputes::new();
+
+            let result = bright_disputes.remove_dispute(1);
+            assert_eq!(result, Err(BrightDisputesError::DisputeNotExist));
+
+            // Create dispute
+            set_value_transferred::<DefaultEnvironment>(10);
+            bright_disputes
+                .create_dispute("https://brightinventions.pl".into(), accounts.bob, 10)
+                .expect("Failed to create a dispute!");
+            let result = bright_disputes.remove_dispute(1);
+            assert_eq!(result, Ok(()));
+
+            let result = bright_disputes.remove_dispute(1);
+            assert_eq!(result, Err(BrightDisputesError::DisputeNotExist));
+
+            // Failed to remove dispute in "Running" state.
+            let mut bright_disputes = create_test_bright_dispute_with_running_dispute();
+            let result = bright_disputes.remove_dispute(1);
+            assert_eq!(result, Err(BrightDisputesError::InvalidDisputeState));
+
+            // Failed to remove dispute in "Ended" state.
+            let mut dispute = bright_disputes
+                .get_dispute(1)
+                .expect("Failed to get dispute!");
+            dispute
+                .end_dispute(Some(DisputeResult::Owner), vec![])
+                .expect("Failed to end dispute!");
+            bright_disputes.update_dispute(dispute.clone());
+            let result = bright_disputes.remove_dispute(1);
+            assert_eq!(result, Err(BrightDisputesError::InvalidDisputeState));
+
+            // Success
+            dispute.close_dispute().expect("Failed to close dispute!");
+            bright_disputes.update_dispute(dispute);
+            let result = bright_disputes.remove_dispute(1);
+            assert_eq!(result, Ok(()));
+        }
+
+        /// Test confirmation of the defendant
+        #[ink::test]
+        fn confirm_defendant() {
+            let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+
+            let mut bright_disputes = BrightDisputes::new();
+
+            let defendant_link = "https://brightinventions.pl/";
 
             // Check when there is no dispute
             let result = bright_disputes.confirm_defendant(1, defendant_link.into());
@@ -947,19 +2238,19 @@ pub mod bright_disputes {
             set_caller::<DefaultEnvironment>(accounts.alice);
 
             let mut bright_disputes = BrightDisputes::new();
-            assert_eq!(bright_disputes.juries_pool.len(), 0);
+            assert_eq!(bright_disputes.juries_pool_len, 0);
             assert!(!bright_disputes.juries.contains(accounts.alice));
 
             // Success
             let result = bright_disputes.register_as_an_active_juror();
             assert_eq!(result, Ok(()));
-            assert_eq!(bright_disputes.juries_pool.len(), 1);
+            assert_eq!(bright_disputes.juries_pool_len, 1);
             assert!(bright_disputes.juries.contains(accounts.alice));
 
             // Failed to register already registered juror
             let result = bright_disputes.register_as_an_active_juror();
             assert_eq!(result, Err(BrightDisputesError::JurorAlreadyRegistered));
-            assert_eq!(bright_disputes.juries_pool.len(), 1);
+            assert_eq!(bright_disputes.juries_pool_len, 1);
             assert!(bright_disputes.juries.contains(accounts.alice));
         }
 
@@ -974,7 +2265,7 @@ pub mod bright_disputes {
             // Failed to unregister juror, no juror in the pool
             let result = bright_disputes.unregister_as_an_active_juror();
             assert_eq!(result, Err(BrightDisputesError::NotRegisteredAsJuror));
-            assert_eq!(bright_disputes.juries_pool.len(), 0);
+            assert_eq!(bright_disputes.juries_pool_len, 0);
             assert!(!bright_disputes.juries.contains(accounts.alice));
 
             bright_disputes
@@ -984,7 +2275,7 @@ pub mod bright_disputes {
             // Success
             let result = bright_disputes.unregister_as_an_active_juror();
             assert_eq!(result, Ok(()));
-            assert_eq!(bright_disputes.juries_pool.len(), 0);
+            assert_eq!(bright_disputes.juries_pool_len, 0);
             assert!(bright_disputes.juries.contains(accounts.alice));
 
             // Failed to unregister juror, juror already unregistered
@@ -992,6 +2283,200 @@ pub mod bright_disputes {
             assert_eq!(result, Err(BrightDisputesError::NotRegisteredAsJuror));
         }
 
+        /// Test removing juries from the middle of the pool
+        #[ink::test]
+        fn juries_pool_remove() {
+            let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+            let mut bright_disputes = BrightDisputes::new();
+            register_valid_juries(&mut bright_disputes);
+
+            // Success, last juror moved in place of the removed one.
+            let result = bright_disputes.remove_juror_from_pool_or_assert(accounts.eve);
+            assert_eq!(result, Ok(()));
+            assert_eq!(
+                bright_disputes.get_juries_pool(),
+                vec![accounts.charlie, accounts.django, accounts.frank]
+            );
+
+            // Failed, juror already removed.
+            let result = bright_disputes.remove_juror_from_pool_or_assert(accounts.eve);
+            assert_eq!(result, Err(BrightDisputesError::NotRegisteredAsJuror));
+
+            // Success, remove the last juror.
+            let result = bright_disputes.remove_juror_from_pool_or_assert(accounts.frank);
+            assert_eq!(result, Ok(()));
+            assert_eq!(
+                bright_disputes.get_juries_pool(),
+                vec![accounts.charlie, accounts.django]
+            );
+
+            // Success, juror can register again.
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            let result = bright_disputes.register_as_an_active_juror();
+            assert_eq!(result, Ok(()));
+            assert_eq!(
+                bright_disputes.get_juries_pool(),
+                vec![accounts.charlie, accounts.django, accounts.eve]
+            );
+        }
+
+        /// Test if the storage cost of drawing the juries doesn't depend on the pool size
+        #[ink::test]
+        fn juries_pool_constant_cost() {
+            let contract_id = ink::env::test::callee::<DefaultEnvironment>();
+
+            let mut storage_usage = Vec::new();
+            for (pool_id, pool_size) in [(1u8, 10u8), (2u8, 200u8)] {
+                let mut bright_disputes = BrightDisputes::new();
+                for i in 0..pool_size {
+                    let mut juror_id = [0u8; 32];
+                    juror_id[0] = pool_id;
+                    juror_id[1] = i;
+                    set_caller::<DefaultEnvironment>(AccountId::from(juror_id));
+                    bright_disputes
+                        .register_as_an_active_juror()
+                        .expect("Failed to register a juror!");
+                }
+
+                let (reads, writes) =
+                    ink::env::test::get_contract_storage_rw::<DefaultEnvironment>(&contract_id);
+
+                let except = vec![bright_disputes.juries_pool.get(0).unwrap()];
+                bright_disputes
+                    .remove_random_juries_from_pool_or_assert(&except, 3)
+                    .expect("Failed to draw juries!");
+                bright_disputes
+                    .assert_juror_not_in_pool(AccountId::from([0xFF; 32]))
+                    .expect("Juror should not be in the pool!");
+
+                let (reads_after, writes_after) =
+                    ink::env::test::get_contract_storage_rw::<DefaultEnvironment>(&contract_id);
+                storage_usage.push((reads_after - reads, writes_after - writes));
+            }
+            assert_eq!(storage_usage[0], storage_usage[1]);
+        }
+
+        /// Test juror suspension after reaching the limit of bans
+        #[ink::test]
+        fn juror_suspension() {
+            let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            set_block_timestamp::<DefaultEnvironment>(0);
+
+            // Juries are suspended after the first ban.
+            let mut bright_disputes = create_test_bright_dispute_with_running_dispute();
+            bright_disputes.suspension_bans_limit = 1;
+            bright_disputes.suspension_time = Some(100);
+            let (judge, juries) = ban_juries_for_missed_vote(&mut bright_disputes, 1);
+
+            // Juries are banned and suspended, judge is back in the pool.
+            let now = ink::env::block_timestamp::<DefaultEnvironment>();
+            for juror in &juries {
+                assert_eq!(bright_disputes.get_juror_stats(*juror).bans(), 1);
+                assert_eq!(
+                    bright_disputes.get_juror_suspension(*juror),
+                    Some(now + 100)
+                );
+                assert!(!bright_disputes.juries_pool_indexes.contains(*juror));
+            }
+            assert!(bright_disputes.juries_pool_indexes.contains(judge));
+
+            // Failed to register, juror is suspended.
+            set_caller::<DefaultEnvironment>(juries[0]);
+            let result = bright_disputes.register_as_an_active_juror();
+            assert_eq!(result, Err(BrightDisputesError::JurorSuspended));
+
+            // Success, suspension ended.
+            set_block_timestamp::<DefaultEnvironment>(now + 100);
+            assert_eq!(bright_disputes.get_juror_suspension(juries[0]), None);
+            let result = bright_disputes.register_as_an_active_juror();
+            assert_eq!(result, Ok(()));
+        }
+
+        /// Test permanent suspension, suspended juror is not released back to the pool.
+        #[ink::test]
+        fn juror_permanent_suspension() {
+            let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            set_block_timestamp::<DefaultEnvironment>(0);
+
+            let mut bright_disputes = create_test_bright_dispute_with_running_dispute();
+            bright_disputes.suspension_bans_limit = 1;
+            bright_disputes.suspension_time = None;
+            let (judge, juries) = ban_juries_for_missed_vote(&mut bright_disputes, 1);
+            for juror in &juries {
+                assert_eq!(
+                    bright_disputes.get_juror_suspension(*juror),
+                    Some(Timestamp::MAX)
+                );
+            }
+            bright_disputes.suspensions.insert(judge, &Timestamp::MAX);
+            bright_disputes
+                .remove_juror_from_pool_or_assert(judge)
+                .expect("Failed to remove judge from the pool!");
+            assert_eq!(bright_disputes.release_juror(judge), Ok(()));
+            assert!(!bright_disputes.juries_pool_indexes.contains(judge));
+            assert_eq!(
+                bright_disputes.add_to_juries_pool(judge),
+                Err(BrightDisputesError::JurorSuspended)
+            );
+        }
+
+        /// Run the dispute to the "Voting" state and reach the deadline, without
+        /// any vote. All juries are banned for missing the deadline. Returns the
+        /// judge and the banned juries.
+        fn ban_juries_for_missed_vote(
+            bright_disputes: &mut BrightDisputes,
+            dispute_id: DisputeId,
+        ) -> (AccountId, Vec<AccountId>) {
+            let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+            register_valid_juries(bright_disputes);
+
+            // Switch to "PickingJuriesAndJudge" state.
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            bright_disputes
+                .process_dispute_round(dispute_id)
+                .expect("Failed to process dispute round!");
+
+            let dispute = bright_disputes
+                .get_dispute(dispute_id)
+                .expect("Failed to get dispute!");
+            let judge = dispute.judge().expect("Judge was not assigned!");
+            let juries = dispute.juries();
+
+            set_value_transferred::<DefaultEnvironment>(10);
+            set_caller::<DefaultEnvironment>(judge);
+            bright_disputes
+                .confirm_judge_participation_in_dispute(dispute_id, vec![])
+                .expect("Failed to confirm judge participation!");
+            for juror in &juries {
+                set_caller::<DefaultEnvironment>(*juror);
+                bright_disputes
+                    .confirm_juror_participation_in_dispute(dispute_id, vec![])
+                    .expect("Failed to confirm juror participation!");
+            }
+
+            // Switch to "Voting" state.
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            bright_disputes
+                .process_dispute_round(dispute_id)
+                .expect("Failed to process dispute round!");
+
+            // Voting deadline is reached, no one voted.
+            let deadline = bright_disputes
+                .get_dispute(dispute_id)
+                .expect("Failed to get dispute!")
+                .dispute_round()
+                .expect("Dispute round not started!")
+                .state_deadline();
+            set_block_timestamp::<DefaultEnvironment>(deadline);
+            bright_disputes
+                .process_dispute_round(dispute_id)
+                .expect("Failed to process dispute round!");
+
+            (judge, juries)
+        }
+
         // Test juries confirmation to the dispute case.
         #[ink::test]
         fn confirm_judge_and_juror_participation_in_dispute() {
@@ -1024,6 +2509,10 @@ pub mod bright_disputes {
                 .expect("Failed to get dispute!");
 
             let judge = dispute.judge().expect("Judge was not assigned!");
+            assert_eq!(
+                bright_disputes.has_confirmed_participation(dispute_id, judge),
+                Ok(false)
+            );
 
             // Fail to confirm judge, invalid escrow
             set_caller::<DefaultEnvironment>(judge);
@@ -1036,6 +2525,10 @@ pub mod bright_disputes {
             set_value_transferred::<DefaultEnvironment>(10);
             let result = bright_disputes.confirm_judge_participation_in_dispute(dispute_id, vec![]);
             assert_eq!(result, Ok(()));
+            assert_eq!(
+                bright_disputes.has_confirmed_participation(dispute_id, judge),
+                Ok(true)
+            );
 
             // Confirm juries
             for juror in &dispute.juries() {
@@ -1054,7 +2547,15 @@ pub mod bright_disputes {
                     bright_disputes.confirm_juror_participation_in_dispute(dispute_id, vec![]),
                     Ok(())
                 );
+                assert_eq!(
+                    bright_disputes.has_confirmed_participation(dispute_id, *juror),
+                    Ok(true)
+                );
             }
+            assert_eq!(
+                bright_disputes.has_confirmed_participation(dispute_id + 1, judge),
+                Err(BrightDisputesError::DisputeNotExist)
+            );
 
             // Failed to confirm twice
             let result = bright_disputes.confirm_juror_participation_in_dispute(dispute_id, vec![]);
@@ -1070,59 +2571,728 @@ pub mod bright_disputes {
                 .expect("Failed to process dispute round!");
         }
 
-        // Check switching to next round when deadline appear.
+        // Test actions, which accounts need to take in their disputes.
         #[ink::test]
-        fn vote() {
-            mock::register_chain_extensions(());
-
+        fn pending_actions() {
             let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
             set_caller::<DefaultEnvironment>(accounts.alice);
+            let mut bright_disputes = BrightDisputes::new();
 
-            let mut bright_disputes = create_test_bright_dispute_with_running_dispute();
-            let dispute_id = 1;
+            // Defendant needs to confirm the dispute.
+            set_value_transferred::<DefaultEnvironment>(10);
+            let dispute_id = bright_disputes
+                .create_dispute("https://brightinventions.pl/".into(), accounts.bob, 10)
+                .expect("Failed to create a dispute!");
+            assert_eq!(
+                bright_disputes.pending_actions(accounts.bob),
+                vec![(dispute_id, ActionKind::ConfirmDefendant, None)]
+            );
+            assert_eq!(bright_disputes.pending_actions(accounts.alice), vec![]);
 
-            // Register charlie, eve, frank  and django as a juries.
+            // Owner needs to assign juries and judge.
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            bright_disputes
+                .confirm_defendant(dispute_id, "".into())
+                .expect("Failed to confirm defendant a dispute!");
             register_valid_juries(&mut bright_disputes);
+            let actions = bright_disputes.pending_actions(accounts.alice);
+            assert_eq!(actions.len(), 1);
+            assert_eq!(actions[0].1, ActionKind::ProcessDisputeRound);
+            assert_eq!(bright_disputes.pending_actions(accounts.bob), vec![]);
 
-            // Switch to "PickingJuriesAndJudge" state.
+            // Juries and judge need to confirm participation.
             set_caller::<DefaultEnvironment>(accounts.alice);
             bright_disputes
                 .process_dispute_round(dispute_id)
-                .expect("Failed to create a dispute!");
-
+                .expect("Failed to process dispute round!");
             let dispute = bright_disputes
                 .get_dispute(dispute_id)
                 .expect("Failed to get dispute!");
+            let deadline = dispute.dispute_round().map(|round| round.state_deadline());
+            let judge = dispute.judge().expect("Judge was not assigned!");
+            assert_eq!(
+                bright_disputes.pending_actions(judge),
+                vec![(dispute_id, ActionKind::ConfirmJudge, deadline)]
+            );
+            for juror in dispute.juries() {
+                assert_eq!(
+                    bright_disputes.pending_actions(juror),
+                    vec![(dispute_id, ActionKind::ConfirmJuror, deadline)]
+                );
+            }
+            assert_eq!(bright_disputes.pending_actions(accounts.alice), vec![]);
 
-            // Assign all juries
-            let assigned_juries = dispute.juries();
-
-            let mut juror_not_assigned = vec![
-                accounts.charlie,
-                accounts.eve,
-                accounts.frank,
-                accounts.django,
-            ];
-            juror_not_assigned.retain(|id| !assigned_juries.contains(id));
-
-            // Confirm juror participation
-            set_caller::<DefaultEnvironment>(assigned_juries[0]);
-            bright_disputes
-                .confirm_juror_participation_in_dispute(dispute_id, vec![])
-                .expect("Failed confirm juries participation!");
-
-            set_caller::<DefaultEnvironment>(assigned_juries[1]);
-            bright_disputes
-                .confirm_juror_participation_in_dispute(dispute_id, vec![])
-                .expect("Failed confirm juries participation!");
-
-            set_caller::<DefaultEnvironment>(assigned_juries[2]);
-            bright_disputes
-                .confirm_juror_participation_in_dispute(dispute_id, vec![])
-                .expect("Failed confirm juries participation!");
-
-            // Assign judge
-            set_caller::<DefaultEnvironment>(juror_not_assigned[0]);
+            // Owner needs to start voting, once everyone confirmed.
+            set_value_transferred::<DefaultEnvironment>(10);
+            set_caller::<DefaultEnvironment>(judge);
+            bright_disputes
+                .confirm_judge_participation_in_dispute(dispute_id, vec![])
+                .expect("Failed to confirm judge participation!");
+            for juror in dispute.juries() {
+                set_caller::<DefaultEnvironment>(juror);
+                bright_disputes
+                    .confirm_juror_participation_in_dispute(dispute_id, vec![])
+                    .expect("Failed to confirm juror participation!");
+                assert_eq!(bright_disputes.pending_actions(juror), vec![]);
+            }
+            let actions = bright_disputes.pending_actions(accounts.alice);
+            assert_eq!(actions.len(), 1);
+            assert_eq!(actions[0].1, ActionKind::ProcessDisputeRound);
+
+            // Juries need to vote.
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            bright_disputes
+                .process_dispute_round(dispute_id)
+                .expect("Failed to process dispute round!");
+            for juror in dispute.juries() {
+                let actions = bright_disputes.pending_actions(juror);
+                assert_eq!(actions.len(), 1);
+                assert_eq!(actions[0].1, ActionKind::Vote);
+            }
+            assert_eq!(bright_disputes.pending_actions(judge), vec![]);
+            assert_eq!(bright_disputes.pending_actions(accounts.alice), vec![]);
+        }
+
+        // Test default judgment, when the defendant ignores the dispute.
+        #[ink::test]
+        fn request_default_judgment() {
+            type Event = <BrightDisputes as ink::reflect::ContractEventBase>::Type;
+
+            let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            set_block_timestamp::<DefaultEnvironment>(0);
+            let mut bright_disputes = BrightDisputes::new_with_rules(
+                3,
+                None,
+                Some(DefaultJudgmentRules {
+                    response_time: 100,
+                    max_escrow: Some(10),
+                }),
+            );
+
+            set_value_transferred::<DefaultEnvironment>(10);
+            let dispute_id = bright_disputes
+                .create_dispute("https://brightinventions.pl/".into(), accounts.bob, 10)
+                .expect("Failed to create a dispute!");
+            set_value_transferred::<DefaultEnvironment>(20);
+            let high_escrow_dispute_id = bright_disputes
+                .create_dispute("https://brightinventions.pl/".into(), accounts.bob, 20)
+                .expect("Failed to create a dispute!");
+
+            // Failed, response deadline not reached.
+            let result = bright_disputes.request_default_judgment(dispute_id);
+            assert_eq!(result, Err(BrightDisputesError::ResponseDeadlineNotReached));
+            assert_eq!(bright_disputes.pending_actions(accounts.alice), vec![]);
+
+            set_block_timestamp::<DefaultEnvironment>(100);
+            assert_eq!(
+                bright_disputes.pending_actions(accounts.alice),
+                vec![(dispute_id, ActionKind::RequestDefaultJudgment, Some(100))]
+            );
+
+            // Failed, escrow is above the threshold.
+            let result = bright_disputes.request_default_judgment(high_escrow_dispute_id);
+            assert_eq!(result, Err(BrightDisputesError::DefaultJudgmentNotAllowed));
+
+            // Failed, only owner can request default judgment.
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            let result = bright_disputes.request_default_judgment(dispute_id);
+            assert_eq!(result, Err(BrightDisputesError::NotAuthorized));
+
+            // Success
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let result = bright_disputes.request_default_judgment(dispute_id);
+            assert_eq!(result, Ok(()));
+
+            let dispute = bright_disputes
+                .get_dispute(dispute_id)
+                .expect("Failed to get dispute!");
+            assert_eq!(dispute.state(), DisputeState::Closed);
+            assert_eq!(dispute.get_dispute_result(), Some(DisputeResult::Owner));
+
+            let event = ink::env::test::recorded_events()
+                .last()
+                .expect("No events emitted!");
+            let event =
+                <Event as Decode>::decode(&mut &event.data[..]).expect("Failed to decode event!");
+            assert!(matches!(
+                event,
+                Event::DefaultJudgmentIssued(DefaultJudgmentIssued { dispute_id: 1, .. })
+            ));
+
+            // Closed dispute can be removed.
+            let result = bright_disputes.remove_dispute(dispute_id);
+            assert_eq!(result, Ok(()));
+        }
+
+        // Test default judgment, when the defendant and juries have confirmed the dispute.
+        #[ink::test]
+        fn default_judgment_after_confirmation() {
+            type Event = <BrightDisputes as ink::reflect::ContractEventBase>::Type;
+
+            let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            set_block_timestamp::<DefaultEnvironment>(0);
+            let mut bright_disputes = BrightDisputes::new_with_rules(
+                3,
+                None,
+                Some(DefaultJudgmentRules {
+                    response_time: 100,
+                    max_escrow: None,
+                }),
+            );
+            set_value_transferred::<DefaultEnvironment>(10);
+            let dispute_id = bright_disputes
+                .create_dispute("https://brightinventions.pl/".into(), accounts.bob, 10)
+                .expect("Failed to create a dispute!");
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            bright_disputes
+                .confirm_defendant(dispute_id, "".into())
+                .expect("Failed to confirm defendant a dispute!");
+
+            // Assign juries and judge, all of them confirm participation.
+            register_valid_juries(&mut bright_disputes);
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            bright_disputes
+                .process_dispute_round(dispute_id)
+                .expect("Failed to process dispute round!");
+            let dispute = bright_disputes
+                .get_dispute(dispute_id)
+                .expect("Failed to get dispute!");
+            set_value_transferred::<DefaultEnvironment>(10);
+            for juror_id in dispute.judge().into_iter().chain(dispute.juries()) {
+                set_caller::<DefaultEnvironment>(juror_id);
+                if Some(juror_id) == dispute.judge() {
+                    bright_disputes
+                        .confirm_judge_participation_in_dispute(dispute_id, vec![])
+                        .expect("Failed to confirm judge participation!");
+                } else {
+                    bright_disputes
+                        .confirm_juror_participation_in_dispute(dispute_id, vec![])
+                        .expect("Failed to confirm juror participation!");
+                }
+            }
+
+            // Failed, the response deadline passed, but the deposits would be lost.
+            set_block_timestamp::<DefaultEnvironment>(100);
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let result = bright_disputes.request_default_judgment(dispute_id);
+            assert_eq!(result, Err(BrightDisputesError::InvalidDisputeState));
+            assert!(!bright_disputes
+                .pending_actions(accounts.alice)
+                .iter()
+                .any(|(_, action, _)| *action == ActionKind::RequestDefaultJudgment));
+
+            let dispute = bright_disputes
+                .get_dispute(dispute_id)
+                .expect("Failed to get dispute!");
+            assert_eq!(dispute.state(), DisputeState::Running);
+            assert_eq!(
+                dispute.deposit(),
+                10 * (2 + 1 + dispute.juries().len() as Balance)
+            );
+            assert!(!ink::env::test::recorded_events()
+                .flat_map(|event| <Event as Decode>::decode(&mut &event.data[..]))
+                .any(|event| matches!(event, Event::DepositPaid(_))));
+        }
+
+        /// Test if default judgments are disabled, unless the rules are given.
+        #[ink::test]
+        fn default_judgment_disabled() {
+            let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            set_block_timestamp::<DefaultEnvironment>(0);
+
+            for mut bright_disputes in [
+                BrightDisputes::new(),
+                BrightDisputes::new_with_suspension_rules(3, None),
+                BrightDisputes::new_with_rules(3, None, None),
+            ] {
+                set_value_transferred::<DefaultEnvironment>(10);
+                let dispute_id = bright_disputes
+                    .create_dispute("https://brightinventions.pl/".into(), accounts.bob, 10)
+                    .expect("Failed to create a dispute!");
+
+                set_block_timestamp::<DefaultEnvironment>(Timestamp::MAX);
+                let result = bright_disputes.request_default_judgment(dispute_id);
+                assert_eq!(result, Err(BrightDisputesError::DefaultJudgmentNotAllowed));
+                assert_eq!(bright_disputes.pending_actions(accounts.alice), vec![]);
+                set_block_timestamp::<DefaultEnvironment>(0);
+            }
+        }
+
+        // Test settlement of the running dispute.
+        #[ink::test]
+        fn settlement() {
+            type Event = <BrightDisputes as ink::reflect::ContractEventBase>::Type;
+
+            let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+
+            let mut bright_disputes = create_test_bright_dispute_with_running_dispute();
+            let dispute_id = 1;
+            register_valid_juries(&mut bright_disputes);
+
+            // Assign juries and judge, judge and one juror confirm participation.
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            bright_disputes
+                .process_dispute_round(dispute_id)
+                .expect("Failed to process dispute round!");
+            let dispute = bright_disputes
+                .get_dispute(dispute_id)
+                .expect("Failed to get dispute!");
+            let judge = dispute.judge().expect("Judge was not assigned!");
+            let juror = dispute.juries()[0];
+            set_value_transferred::<DefaultEnvironment>(10);
+            set_caller::<DefaultEnvironment>(judge);
+            bright_disputes
+                .confirm_judge_participation_in_dispute(dispute_id, vec![])
+                .expect("Failed to confirm judge participation!");
+            set_caller::<DefaultEnvironment>(juror);
+            bright_disputes
+                .confirm_juror_participation_in_dispute(dispute_id, vec![])
+                .expect("Failed to confirm juror participation!");
+
+            // Failed, juror can't propose settlement.
+            let result = bright_disputes.propose_settlement(dispute_id, 70);
+            assert_eq!(result, Err(BrightDisputesError::NotAuthorized));
+
+            // Owner proposes, defendant accepts.
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            bright_disputes
+                .propose_settlement(dispute_id, 70)
+                .expect("Failed to propose settlement!");
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            let result = bright_disputes.accept_settlement(dispute_id);
+            assert_eq!(result, Ok(()));
+
+            let dispute = bright_disputes
+                .get_dispute(dispute_id)
+                .expect("Failed to get dispute!");
+            assert_eq!(dispute.state(), DisputeState::Closed);
+            assert_eq!(bright_disputes.juries_pool_len, 4);
+
+            // Deposit: 4 * 10, confirmed juries get back 10 + 1 fee each,
+            // parties split the rest 70 / 30.
+            let payouts: Vec<(AccountId, Balance)> = ink::env::test::recorded_events()
+                .flat_map(|event| <Event as Decode>::decode(&mut &event.data[..]))
+                .filter_map(|event| match event {
+                    Event::DepositPaid(event) => Some((event.account_id, event.amount)),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(
+                payouts,
+                vec![
+                    (judge, 11),
+                    (juror, 11),
+                    (accounts.alice, 12),
+                    (accounts.bob, 6)
+                ]
+            );
+        }
+
+        // Test settlement of the dispute, which the defendant hasn't confirmed.
+        #[ink::test]
+        fn settlement_of_created_dispute() {
+            type Event = <BrightDisputes as ink::reflect::ContractEventBase>::Type;
+
+            let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let mut bright_disputes = BrightDisputes::new();
+            set_value_transferred::<DefaultEnvironment>(10);
+            let dispute_id = bright_disputes
+                .create_dispute("https://brightinventions.pl/".into(), accounts.bob, 10)
+                .expect("Failed to create a dispute!");
+
+            // Defendant proposes to get all the funds, owner accepts.
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            bright_disputes
+                .propose_settlement(dispute_id, 0)
+                .expect("Failed to propose settlement!");
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let result = bright_disputes.accept_settlement(dispute_id);
+            assert_eq!(result, Ok(()));
+
+            let dispute = bright_disputes
+                .get_dispute(dispute_id)
+                .expect("Failed to get dispute!");
+            assert_eq!(dispute.state(), DisputeState::Closed);
+
+            // Defendant has deposited nothing, owner gets back the whole escrow.
+            let payouts: Vec<(AccountId, Balance)> = ink::env::test::recorded_events()
+                .flat_map(|event| <Event as Decode>::decode(&mut &event.data[..]))
+                .filter_map(|event| match event {
+                    Event::DepositPaid(event) => Some((event.account_id, event.amount)),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(payouts, vec![(accounts.alice, 10)]);
+        }
+
+        // Test attaching encrypted evidence for the juries.
+        #[ink::test]
+        fn submit_evidence() {
+            let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+
+            let mut bright_disputes = create_test_bright_dispute_with_running_dispute();
+            let dispute_id = 1;
+
+            // Register charlie, django, eve and frank as a juries.
+            register_valid_juries(&mut bright_disputes);
+
+            // Switch to "PickingJuriesAndJudge" state.
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            bright_disputes
+                .process_dispute_round(dispute_id)
+                .expect("Failed to process dispute round!");
+
+            let dispute = bright_disputes
+                .get_dispute(dispute_id)
+                .expect("Failed to get dispute!");
+            let juries = dispute.juries();
+
+            // Failed, juror has not confirmed participation yet.
+            let result =
+                bright_disputes.submit_evidence(dispute_id, vec![1], vec![(juries[0], vec![2])]);
+            assert_eq!(
+                result,
+                Err(BrightDisputesError::JurorHasNotConfirmedDispute)
+            );
+
+            set_caller::<DefaultEnvironment>(juries[0]);
+            set_value_transferred::<DefaultEnvironment>(10);
+            bright_disputes
+                .confirm_juror_participation_in_dispute(dispute_id, vec![0])
+                .expect("Failed confirm juror participation!");
+
+            // Failed, only owner or defendant can attach an evidence.
+            let result =
+                bright_disputes.submit_evidence(dispute_id, vec![1], vec![(juries[0], vec![2])]);
+            assert_eq!(result, Err(BrightDisputesError::NotAuthorized));
+
+            // Failed, evidence can be attached only for assigned juries.
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let result =
+                bright_disputes.submit_evidence(dispute_id, vec![1], vec![(accounts.bob, vec![2])]);
+            assert_eq!(
+                result,
+                Err(BrightDisputesError::JurorIsNotAssignedToDispute)
+            );
+
+            // Success, owner and defendant attach an evidence.
+            let result =
+                bright_disputes.submit_evidence(dispute_id, vec![1], vec![(juries[0], vec![2])]);
+            assert_eq!(result, Ok(()));
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            let result =
+                bright_disputes.submit_evidence(dispute_id, vec![3], vec![(juries[0], vec![4])]);
+            assert_eq!(result, Ok(()));
+
+            let evidences = bright_disputes.get_evidence(dispute_id, juries[0]);
+            assert_eq!(
+                evidences,
+                vec![
+                    Evidence::create(accounts.alice, vec![1], vec![2]),
+                    Evidence::create(accounts.bob, vec![3], vec![4])
+                ]
+            );
+            assert!(bright_disputes
+                .get_evidence(dispute_id, juries[1])
+                .is_empty());
+
+            // Failed, evidence is too large.
+            let result = bright_disputes.submit_evidence(
+                dispute_id,
+                vec![3],
+                vec![(juries[0], vec![0; BrightDisputes::EVIDENCE_MAX_SIZE + 1])],
+            );
+            assert_eq!(result, Err(BrightDisputesError::EvidenceTooLarge));
+
+            // Failed, defendant reached the limit of evidences for the juror.
+            for _ in 1..BrightDisputes::EVIDENCES_PER_PARTY_LIMIT {
+                bright_disputes
+                    .submit_evidence(dispute_id, vec![3], vec![(juries[0], vec![4])])
+                    .expect("Failed to submit evidence!");
+            }
+            let result =
+                bright_disputes.submit_evidence(dispute_id, vec![3], vec![(juries[0], vec![4])]);
+            assert_eq!(result, Err(BrightDisputesError::EvidenceLimitReached));
+
+            // Owner can still attach evidences.
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let result =
+                bright_disputes.submit_evidence(dispute_id, vec![1], vec![(juries[0], vec![2])]);
+            assert_eq!(result, Ok(()));
+
+            // Failed, evidence can't be attached once the dispute is closed.
+            bright_disputes
+                .propose_settlement(dispute_id, 70)
+                .expect("Failed to propose settlement!");
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            bright_disputes
+                .accept_settlement(dispute_id)
+                .expect("Failed to accept settlement!");
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let result =
+                bright_disputes.submit_evidence(dispute_id, vec![1], vec![(juries[0], vec![2])]);
+            assert_eq!(result, Err(BrightDisputesError::InvalidDisputeState));
+        }
+
+        // Check switching to next round when deadline appear.
+        #[cfg(any(feature = "baby-liminal", feature = "mock-verifier"))]
+        #[ink::test]
+        fn vote() {
+            register_verifier();
+
+            let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+
+            let mut bright_disputes = create_test_bright_dispute_with_running_dispute();
+            let dispute_id = 1;
+
+            // Register charlie, eve, frank  and django as a juries.
+            register_valid_juries(&mut bright_disputes);
+
+            // Switch to "PickingJuriesAndJudge" state.
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            bright_disputes
+                .process_dispute_round(dispute_id)
+                .expect("Failed to create a dispute!");
+
+            let dispute = bright_disputes
+                .get_dispute(dispute_id)
+                .expect("Failed to get dispute!");
+
+            // Assign all juries
+            let assigned_juries = dispute.juries();
+
+            let mut juror_not_assigned = vec![
+                accounts.charlie,
+                accounts.eve,
+                accounts.frank,
+                accounts.django,
+            ];
+            juror_not_assigned.retain(|id| !assigned_juries.contains(id));
+
+            // Confirm juror participation
+            set_caller::<DefaultEnvironment>(assigned_juries[0]);
+            bright_disputes
+                .confirm_juror_participation_in_dispute(dispute_id, vec![])
+                .expect("Failed confirm juries participation!");
+
+            set_caller::<DefaultEnvironment>(assigned_juries[1]);
+            bright_disputes
+                .confirm_juror_participation_in_dispute(dispute_id, vec![])
+                .expect("Failed confirm juries participation!");
+
+            set_caller::<DefaultEnvironment>(assigned_juries[2]);
+            bright_disputes
+                .confirm_juror_participation_in_dispute(dispute_id, vec![])
+                .expect("Failed confirm juries participation!");
+
+            // Assign judge
+            set_caller::<DefaultEnvironment>(juror_not_assigned[0]);
+            bright_disputes
+                .confirm_judge_participation_in_dispute(dispute_id, vec![])
+                .expect("Failed to confirm judge participation!");
+
+            // Switch state to "Voting" state
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let result = bright_disputes.process_dispute_round(dispute_id);
+            assert_eq!(result, Ok(()));
+
+            // Juries voting
+            set_caller::<DefaultEnvironment>(assigned_juries[0]);
+            bright_disputes
+                .vote(dispute_id, [0u64; 4], [0u64; 4], vec![])
+                .expect("Failed to vote");
+            set_caller::<DefaultEnvironment>(assigned_juries[1]);
+            bright_disputes
+                .vote(dispute_id, [0u64; 4], [0u64; 4], vec![])
+                .expect("Failed to vote");
+            set_caller::<DefaultEnvironment>(assigned_juries[2]);
+            bright_disputes
+                .vote(dispute_id, [0u64; 4], [0u64; 4], vec![])
+                .expect("Failed to vote");
+
+            // Failed, vote is changed only with `change_vote`.
+            let result = bright_disputes.vote(dispute_id, [1u64; 4], [0u64; 4], vec![]);
+            assert_eq!(result, Err(BrightDisputesError::JurorAlreadyVoted));
+
+            // Failed, changed vote has to be encrypted with a fresh key.
+            let result =
+                bright_disputes.change_vote(dispute_id, vec![], [1u64; 4], [2u64; 4], vec![]);
+            assert_eq!(result, Err(BrightDisputesError::VotePublicKeyReused));
+
+            // Juror changes the vote, it replaces the previous one.
+            let result =
+                bright_disputes.change_vote(dispute_id, vec![1], [1u64; 4], [2u64; 4], vec![]);
+            assert_eq!(result, Ok(()));
+            let dispute = bright_disputes
+                .get_dispute(dispute_id)
+                .expect("Failed to get dispute!");
+            assert_eq!(dispute.votes().len(), 3);
+            assert_eq!(
+                dispute.votes()[2],
+                Vote::create_with_public_key(assigned_juries[2], [1u64; 4], vec![1])
+            );
+            assert_eq!(dispute.votes_hash(), [2u64; 4]);
+
+            // Failed, key of the previous vote can't be used again.
+            let result =
+                bright_disputes.change_vote(dispute_id, vec![1], [0u64; 4], [3u64; 4], vec![]);
+            assert_eq!(result, Err(BrightDisputesError::VotePublicKeyReused));
+
+            // Failed, only juror of the dispute can change the vote.
+            set_caller::<DefaultEnvironment>(juror_not_assigned[0]);
+            let result =
+                bright_disputes.change_vote(dispute_id, vec![2], [0u64; 4], [3u64; 4], vec![]);
+            assert_eq!(result, Err(BrightDisputesError::NotAuthorized));
+
+            // Switch state to "CountingTheVotes" state
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let result = bright_disputes.process_dispute_round(dispute_id);
+            assert_eq!(result, Ok(()));
+        }
+
+        // Check switching to next round can only be done by owner.
+        #[ink::test]
+        fn process_dispute_round_owner_call() {
+            let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+
+            let mut bright_disputes = create_test_bright_dispute_with_running_dispute();
+            let dispute_id = 1;
+
+            // Register charlie, eve, frank  and django as a juries.
+            register_valid_juries(&mut bright_disputes);
+
+            // Failed, defendant can not switch the state.
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            let result = bright_disputes.process_dispute_round(dispute_id);
+            assert_eq!(result, Err(BrightDisputesError::NotAuthorized));
+
+            // Success
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let result = bright_disputes.process_dispute_round(dispute_id);
+            assert_eq!(result, Ok(()));
+        }
+
+        // Check events emitted when dispute round is processed.
+        #[ink::test]
+        fn process_dispute_round_events() {
+            type Event = <BrightDisputes as ink::reflect::ContractEventBase>::Type;
+
+            let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+
+            let mut bright_disputes = create_test_bright_dispute_with_running_dispute();
+            let dispute_id = 1;
+            register_valid_juries(&mut bright_disputes);
+
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            bright_disputes
+                .process_dispute_round(dispute_id)
+                .expect("Failed to process dispute round!");
+
+            let events: Vec<Event> = ink::env::test::recorded_events()
+                .map(|event| {
+                    <Event as Decode>::decode(&mut &event.data[..])
+                        .expect("Failed to decode event!")
+                })
+                .collect();
+
+            assert!(matches!(events[0], Event::DisputeRaised(_)));
+            assert!(matches!(events[1], Event::DefendantConfirmDispute(_)));
+            assert!(matches!(
+                &events[2],
+                Event::RoundStateChanged(RoundStateChanged {
+                    state: RoundState::AssignJuriesAndJudge,
+                    ..
+                })
+            ));
+
+            let dispute = bright_disputes
+                .get_dispute(dispute_id)
+                .expect("Failed to get dispute!");
+            let assigned: Vec<AccountId> = events
+                .iter()
+                .filter_map(|event| match event {
+                    Event::JurorAssigned(event) => Some(event.juror_id),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(assigned, dispute.juries());
+            assert!(events.iter().any(|event| matches!(
+                event,
+                Event::JudgeAssigned(JudgeAssigned { judge_id, .. }) if Some(*judge_id) == dispute.judge()
+            )));
+            assert!(matches!(
+                events.last(),
+                Some(Event::RoundStateChanged(RoundStateChanged {
+                    state: RoundState::PickingJuriesAndJudge,
+                    ..
+                }))
+            ));
+        }
+
+        // Check dispute round progress
+        #[cfg(any(feature = "baby-liminal", feature = "mock-verifier"))]
+        #[ink::test]
+        fn process_dispute_round() {
+            register_verifier();
+
+            let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+
+            let mut bright_disputes = create_test_bright_dispute_with_running_dispute();
+            let dispute_id = 1;
+
+            // Register charlie, eve, frank  and django as a juries.
+            register_valid_juries(&mut bright_disputes);
+
+            // Switch to "PickingJuriesAndJudge" state.
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            bright_disputes
+                .process_dispute_round(dispute_id)
+                .expect("Failed to create a dispute!");
+
+            let dispute = bright_disputes
+                .get_dispute(dispute_id)
+                .expect("Failed to get dispute!");
+
+            // Assign all juries
+            let assigned_juries = dispute.juries();
+
+            let mut juror_not_assigned = vec![
+                accounts.charlie,
+                accounts.eve,
+                accounts.frank,
+                accounts.django,
+            ];
+            juror_not_assigned.retain(|id| !assigned_juries.contains(id));
+
+            // Confirm juror participation
+            set_caller::<DefaultEnvironment>(assigned_juries[0]);
+            bright_disputes
+                .confirm_juror_participation_in_dispute(dispute_id, vec![])
+                .expect("Failed confirm juries participation!");
+
+            set_caller::<DefaultEnvironment>(assigned_juries[1]);
+            bright_disputes
+                .confirm_juror_participation_in_dispute(dispute_id, vec![])
+                .expect("Failed confirm juries participation!");
+
+            set_caller::<DefaultEnvironment>(assigned_juries[2]);
+            bright_disputes
+                .confirm_juror_participation_in_dispute(dispute_id, vec![])
+                .expect("Failed confirm juries participation!");
+
+            // Assign judge
+            set_caller::<DefaultEnvironment>(juror_not_assigned[0]);
             bright_disputes
                 .confirm_judge_participation_in_dispute(dispute_id, vec![])
                 .expect("Failed to confirm judge participation!");
@@ -1146,88 +3316,348 @@ pub mod bright_disputes {
                 .vote(dispute_id, [0u64; 4], [0u64; 4], vec![])
                 .expect("Failed to vote");
 
-            // Switch state to "CountingTheVotes" state
+            // Switch state to CountingTheVotes
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let result = bright_disputes.process_dispute_round(dispute_id);
+            assert_eq!(result, Ok(()));
+
+            // Failed to count the votes, only judge can do it.
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let result = bright_disputes.issue_the_verdict(
+                dispute_id,
+                0,
+                0,
+                Verdict::Positive,
+                [0u64; 4],
+                vec![],
+                vec![],
+                vec![],
+            );
+            assert_eq!(result, Err(BrightDisputesError::NotAuthorized));
+
+            // All 3 votes are needed for the majority.
+            let result = bright_disputes.get_verdict_thresholds(dispute_id);
+            assert_eq!(result, Ok((3, 0)));
+
+            // Failed to count the votes, thresholds don't match the number of votes.
+            set_caller::<DefaultEnvironment>(juror_not_assigned[0]);
+            let hashed_votes = recorded_votes_hash(&bright_disputes, dispute_id);
+            let result = bright_disputes.issue_the_verdict(
+                dispute_id,
+                0,
+                2,
+                Verdict::Positive,
+                hashed_votes,
+                vec![],
+                vec![],
+                vec![],
+            );
+            assert_eq!(result, Err(BrightDisputesError::InvalidMajorityThreshold));
+
+            // Failed to count the votes, verdict is not made for the recorded votes.
+            let result = bright_disputes.issue_the_verdict(
+                dispute_id,
+                0,
+                3,
+                Verdict::Positive,
+                [0u64; 4],
+                vec![],
+                vec![],
+                vec![],
+            );
+            assert_eq!(result, Err(BrightDisputesError::InvalidVotesHash));
+
+            // Count the votes, dispute ends
+            let result = bright_disputes.issue_the_verdict(
+                dispute_id,
+                0,
+                3,
+                Verdict::Positive,
+                hashed_votes,
+                vec![],
+                vec![],
+                vec![],
+            );
+            assert_eq!(result, Ok(()));
+        }
+
+        /// Create a dispute in the commit-reveal voting mode and switch it to the "Voting"
+        /// state. Returns dispute id and assigned juries.
+        fn create_commit_reveal_dispute_in_voting_state(
+            bright_disputes: &mut BrightDisputes,
+            majority_threshold: Option<MajorityThreshold>,
+        ) -> (DisputeId, Vec<AccountId>) {
+            let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            set_value_transferred::<DefaultEnvironment>(10);
+
+            let dispute_id = bright_disputes
+                .create_dispute_with_voting_mode(
+                    "".into(),
+                    accounts.bob,
+                    10,
+                    VotingMode::CommitReveal,
+                    majority_threshold,
+                )
+                .expect("Failed to create a dispute!");
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            bright_disputes
+                .confirm_defendant(dispute_id, "".into())
+                .expect("Failed to confirm defendant a dispute!");
+
+            register_valid_juries(bright_disputes);
+
+            // Switch to "PickingJuriesAndJudge" state.
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            bright_disputes
+                .process_dispute_round(dispute_id)
+                .expect("Failed to process dispute round!");
+
+            // Votes are counted by the contract, judge is not assigned.
+            let dispute = bright_disputes
+                .get_dispute(dispute_id)
+                .expect("Failed to get dispute!");
+            assert_eq!(dispute.judge(), None);
+            let assigned_juries = dispute.juries();
+
+            for juror_id in &assigned_juries {
+                set_caller::<DefaultEnvironment>(*juror_id);
+                bright_disputes
+                    .confirm_juror_participation_in_dispute(dispute_id, vec![])
+                    .expect("Failed confirm juries participation!");
+            }
+
+            // Switch state to "Voting" state
             set_caller::<DefaultEnvironment>(accounts.alice);
             let result = bright_disputes.process_dispute_round(dispute_id);
             assert_eq!(result, Ok(()));
+
+            (dispute_id, assigned_juries)
         }
 
-        // Check switching to next round can only be done by owner.
+        /// Test the whole dispute in the commit-reveal voting mode.
+        #[ink::test]
+        fn process_dispute_round_commit_reveal() {
+            let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+            let mut bright_disputes = BrightDisputes::new();
+            let (dispute_id, assigned_juries) =
+                create_commit_reveal_dispute_in_voting_state(&mut bright_disputes, None);
+
+            // Failed, private vote in commit-reveal mode.
+            set_caller::<DefaultEnvironment>(assigned_juries[0]);
+            let result = bright_disputes.vote(dispute_id, [0u64; 4], [0u64; 4], vec![]);
+            assert_eq!(result, Err(BrightDisputesError::InvalidVotingMode));
+
+            // Juror changes the vote before the voting ends.
+            let salt = [3u8; 32];
+            set_caller::<DefaultEnvironment>(assigned_juries[0]);
+            bright_disputes
+                .commit_vote(dispute_id, Vote::commitment(assigned_juries[0], 0, salt))
+                .expect("Failed to commit the vote");
+
+            // Juries commit their votes
+            for juror_id in &assigned_juries {
+                set_caller::<DefaultEnvironment>(*juror_id);
+                bright_disputes
+                    .commit_vote(dispute_id, Vote::commitment(*juror_id, 1, salt))
+                    .expect("Failed to commit the vote");
+            }
+
+            // Switch state to "Revealing" state
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let result = bright_disputes.process_dispute_round(dispute_id);
+            assert_eq!(result, Ok(()));
+            assert_eq!(
+                bright_disputes.pending_actions(assigned_juries[0]),
+                vec![(dispute_id, ActionKind::RevealVote, Some(24 * 3600 * 1000))]
+            );
+
+            // Failed, vote doesn't match the commitment.
+            set_caller::<DefaultEnvironment>(assigned_juries[0]);
+            let result = bright_disputes.reveal_vote(dispute_id, 0, salt);
+            assert_eq!(result, Err(BrightDisputesError::InvalidVoteReveal));
+
+            // Failed, not all juries revealed their votes.
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let result = bright_disputes.process_dispute_round(dispute_id);
+            assert_eq!(
+                result,
+                Err(BrightDisputesError::JuriesNotRevealed(
+                    assigned_juries.clone()
+                ))
+            );
+
+            for juror_id in &assigned_juries {
+                set_caller::<DefaultEnvironment>(*juror_id);
+                bright_disputes
+                    .reveal_vote(dispute_id, 1, salt)
+                    .expect("Failed to reveal the vote");
+            }
+
+            // Votes are counted by the contract, dispute ends.
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let result = bright_disputes.process_dispute_round(dispute_id);
+            assert_eq!(result, Ok(()));
+
+            let dispute = bright_disputes
+                .get_dispute(dispute_id)
+                .expect("Failed to get dispute!");
+            assert_eq!(dispute.state(), DisputeState::Closed);
+            assert_eq!(dispute.get_dispute_result(), Some(DisputeResult::Owner));
+        }
+
+        /// Verdict for one of the parties can't be applied, when all juries abstained.
+        #[ink::test]
+        fn commit_reveal_verdict_without_votes() {
+            let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+            let mut bright_disputes = BrightDisputes::new();
+            let (dispute_id, assigned_juries) =
+                create_commit_reveal_dispute_in_voting_state(&mut bright_disputes, None);
+
+            let salt = [6u8; 32];
+            for juror_id in &assigned_juries {
+                set_caller::<DefaultEnvironment>(*juror_id);
+                bright_disputes
+                    .commit_vote(dispute_id, Vote::commitment(*juror_id, Vote::ABSTAIN, salt))
+                    .expect("Failed to commit the vote");
+            }
+
+            // Switch state to "Revealing" state
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let result = bright_disputes.process_dispute_round(dispute_id);
+            assert_eq!(result, Ok(()));
+
+            for juror_id in &assigned_juries {
+                set_caller::<DefaultEnvironment>(*juror_id);
+                bright_disputes
+                    .reveal_vote(dispute_id, Vote::ABSTAIN, salt)
+                    .expect("Failed to reveal the vote");
+            }
+
+            // Failed, no votes for the positive or negative verdict.
+            let mut dispute = bright_disputes
+                .get_dispute(dispute_id)
+                .expect("Failed to get dispute!");
+            assert_eq!(dispute.number_of_votes(), 0);
+            for verdict in [Verdict::Positive, Verdict::Negative] {
+                let result = bright_disputes.apply_verdict(&mut dispute, verdict, vec![]);
+                assert_eq!(result, Err(BrightDisputesError::MajorityOfVotesNotReached));
+            }
+
+            // Votes are counted by the contract, majority is not reached.
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let result = bright_disputes.process_dispute_round(dispute_id);
+            assert_eq!(result, Ok(()));
+            let dispute = bright_disputes
+                .get_dispute(dispute_id)
+                .expect("Failed to get dispute!");
+            assert_eq!(dispute.state(), DisputeState::Running);
+            assert_eq!(dispute.get_dispute_result(), None);
+        }
+
+        /// Juror who abstained is not counted to the majority, isn't banned and gets a reduced fee.
         #[ink::test]
-        fn process_dispute_round_owner_call() {
+        fn commit_reveal_abstain() {
+            type Event = <BrightDisputes as ink::reflect::ContractEventBase>::Type;
             let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
-            set_caller::<DefaultEnvironment>(accounts.alice);
-
-            let mut bright_disputes = create_test_bright_dispute_with_running_dispute();
-            let dispute_id = 1;
-
-            // Register charlie, eve, frank  and django as a juries.
-            register_valid_juries(&mut bright_disputes);
+            let mut bright_disputes = BrightDisputes::new();
+            let (dispute_id, assigned_juries) =
+                create_commit_reveal_dispute_in_voting_state(&mut bright_disputes, None);
+
+            let salt = [5u8; 32];
+            let votes = [Vote::ABSTAIN, 1, 1];
+            for (juror_id, vote) in assigned_juries.iter().zip(votes) {
+                set_caller::<DefaultEnvironment>(*juror_id);
+                bright_disputes
+                    .commit_vote(dispute_id, Vote::commitment(*juror_id, vote, salt))
+                    .expect("Failed to commit the vote");
+            }
 
-            // Failed, defendant can not switch the state.
-            set_caller::<DefaultEnvironment>(accounts.bob);
+            // Switch state to "Revealing" state
+            set_caller::<DefaultEnvironment>(accounts.alice);
             let result = bright_disputes.process_dispute_round(dispute_id);
-            assert_eq!(result, Err(BrightDisputesError::NotAuthorized));
+            assert_eq!(result, Ok(()));
 
-            // Success
+            for (juror_id, vote) in assigned_juries.iter().zip(votes) {
+                set_caller::<DefaultEnvironment>(*juror_id);
+                bright_disputes
+                    .reveal_vote(dispute_id, vote, salt)
+                    .expect("Failed to reveal the vote");
+            }
+
+            // Votes are counted by the contract, dispute ends.
             set_caller::<DefaultEnvironment>(accounts.alice);
             let result = bright_disputes.process_dispute_round(dispute_id);
             assert_eq!(result, Ok(()));
-        }
 
-        // Check dispute round progress
-        #[ink::test]
-        fn process_dispute_round() {
-            mock::register_chain_extensions(());
+            let dispute = bright_disputes
+                .get_dispute(dispute_id)
+                .expect("Failed to get dispute!");
+            assert_eq!(dispute.get_dispute_result(), Some(DisputeResult::Owner));
+            assert_eq!(dispute.banned(), vec![]);
+
+            let stats = bright_disputes.get_juror_stats(assigned_juries[0]);
+            assert_eq!(stats.votes_with_majority(), 0);
+            assert_eq!(stats.bans(), 0);
+            let stats = bright_disputes.get_juror_stats(assigned_juries[1]);
+            assert_eq!(stats.votes_with_majority(), 1);
+
+            let payouts: Vec<(AccountId, Balance)> = ink::env::test::recorded_events()
+                .flat_map(|event| <Event as Decode>::decode(&mut &event.data[..]))
+                .filter_map(|event| match event {
+                    Event::DepositPaid(event) => Some((event.account_id, event.amount)),
+                    _ => None,
+                })
+                .collect();
+            // Deposit: 5 * 10, juror who abstained gets a half share.
+            assert_eq!(
+                payouts,
+                vec![
+                    (assigned_juries[0], 10),
+                    (assigned_juries[1], 20),
+                    (assigned_juries[2], 20),
+                ]
+            );
+        }
 
+        /// Move the running private voting dispute to the "Voting" state, returns its id,
+        /// juries and judge.
+        #[cfg(any(feature = "baby-liminal", feature = "mock-verifier"))]
+        fn create_private_dispute_in_voting_state(
+            bright_disputes: &mut BrightDisputes,
+        ) -> (DisputeId, Vec<AccountId>, AccountId) {
             let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
-            set_caller::<DefaultEnvironment>(accounts.alice);
-
-            let mut bright_disputes = create_test_bright_dispute_with_running_dispute();
             let dispute_id = 1;
-
-            // Register charlie, eve, frank  and django as a juries.
-            register_valid_juries(&mut bright_disputes);
+            register_valid_juries(bright_disputes);
 
             // Switch to "PickingJuriesAndJudge" state.
             set_caller::<DefaultEnvironment>(accounts.alice);
             bright_disputes
                 .process_dispute_round(dispute_id)
-                .expect("Failed to create a dispute!");
+                .expect("Failed to process dispute round!");
 
             let dispute = bright_disputes
                 .get_dispute(dispute_id)
                 .expect("Failed to get dispute!");
-
-            // Assign all juries
             let assigned_juries = dispute.juries();
-
-            let mut juror_not_assigned = vec![
+            let judge_id = [
                 accounts.charlie,
                 accounts.eve,
                 accounts.frank,
                 accounts.django,
-            ];
-            juror_not_assigned.retain(|id| !assigned_juries.contains(id));
-
-            // Confirm juror participation
-            set_caller::<DefaultEnvironment>(assigned_juries[0]);
-            bright_disputes
-                .confirm_juror_participation_in_dispute(dispute_id, vec![])
-                .expect("Failed confirm juries participation!");
-
-            set_caller::<DefaultEnvironment>(assigned_juries[1]);
-            bright_disputes
-                .confirm_juror_participation_in_dispute(dispute_id, vec![])
-                .expect("Failed confirm juries participation!");
-
-            set_caller::<DefaultEnvironment>(assigned_juries[2]);
-            bright_disputes
-                .confirm_juror_participation_in_dispute(dispute_id, vec![])
-                .expect("Failed confirm juries participation!");
-
-            // Assign judge
-            set_caller::<DefaultEnvironment>(juror_not_assigned[0]);
+            ]
+            .into_iter()
+            .find(|id| !assigned_juries.contains(id))
+            .expect("Failed to find judge!");
+
+            for juror_id in &assigned_juries {
+                set_caller::<DefaultEnvironment>(*juror_id);
+                bright_disputes
+                    .confirm_juror_participation_in_dispute(dispute_id, vec![])
+                    .expect("Failed confirm juries participation!");
+            }
+            set_caller::<DefaultEnvironment>(judge_id);
             bright_disputes
                 .confirm_judge_participation_in_dispute(dispute_id, vec![])
                 .expect("Failed to confirm judge participation!");
@@ -1237,56 +3667,257 @@ pub mod bright_disputes {
             let result = bright_disputes.process_dispute_round(dispute_id);
             assert_eq!(result, Ok(()));
 
-            // Juries voting
+            (dispute_id, assigned_juries, judge_id)
+        }
+
+        /// Juror who abstained in the private voting mode is not counted to the majority,
+        /// isn't banned and gets a reduced fee.
+        #[cfg(any(feature = "baby-liminal", feature = "mock-verifier"))]
+        #[ink::test]
+        fn private_abstain() {
+            type Event = <BrightDisputes as ink::reflect::ContractEventBase>::Type;
+            register_verifier();
+
+            let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+            let mut bright_disputes = create_test_bright_dispute_with_running_dispute();
+            let (dispute_id, assigned_juries, judge_id) =
+                create_private_dispute_in_voting_state(&mut bright_disputes);
+
+            // Failed, only juror can abstain.
+            set_caller::<DefaultEnvironment>(judge_id);
+            let result = bright_disputes.abstain(dispute_id);
+            assert_eq!(result, Err(BrightDisputesError::NotAuthorized));
+
+            // First juror abstains, it can't vote after that.
             set_caller::<DefaultEnvironment>(assigned_juries[0]);
-            bright_disputes
-                .vote(dispute_id, [0u64; 4], [0u64; 4], vec![])
-                .expect("Failed to vote");
-            set_caller::<DefaultEnvironment>(assigned_juries[1]);
-            bright_disputes
-                .vote(dispute_id, [0u64; 4], [0u64; 4], vec![])
-                .expect("Failed to vote");
-            set_caller::<DefaultEnvironment>(assigned_juries[2]);
-            bright_disputes
-                .vote(dispute_id, [0u64; 4], [0u64; 4], vec![])
-                .expect("Failed to vote");
+            let result = bright_disputes.abstain(dispute_id);
+            assert_eq!(result, Ok(()));
+            let result = bright_disputes.abstain(dispute_id);
+            assert_eq!(result, Err(BrightDisputesError::JurorAlreadyVoted));
+            let result = bright_disputes.vote(dispute_id, [0u64; 4], [0u64; 4], vec![]);
+            assert_eq!(result, Err(BrightDisputesError::JurorAlreadyVoted));
+
+            for juror_id in &assigned_juries[1..] {
+                set_caller::<DefaultEnvironment>(*juror_id);
+                bright_disputes
+                    .vote(dispute_id, [0u64; 4], [0u64; 4], vec![])
+                    .expect("Failed to vote");
+            }
 
-            // Switch state to CountingTheVotes
+            // Failed, juror who voted can't abstain.
+            let result = bright_disputes.abstain(dispute_id);
+            assert_eq!(result, Err(BrightDisputesError::JurorAlreadyVoted));
+
+            // Switch state to "CountingTheVotes" state, juror who abstained has acted.
             set_caller::<DefaultEnvironment>(accounts.alice);
             let result = bright_disputes.process_dispute_round(dispute_id);
             assert_eq!(result, Ok(()));
 
-            // Failed to count the votes, only judge can do it.
-            set_caller::<DefaultEnvironment>(accounts.alice);
+            // Only 2 votes are counted, both are needed for the majority.
+            let result = bright_disputes.get_verdict_thresholds(dispute_id);
+            assert_eq!(result, Ok((2, 0)));
+
+            set_caller::<DefaultEnvironment>(judge_id);
+            let hashed_votes = recorded_votes_hash(&bright_disputes, dispute_id);
             let result = bright_disputes.issue_the_verdict(
                 dispute_id,
                 0,
-                0,
+                2,
                 Verdict::Positive,
-                [0u64; 4],
+                hashed_votes,
+                vec![],
                 vec![],
                 vec![],
             );
-            assert_eq!(result, Err(BrightDisputesError::NotAuthorized));
+            assert_eq!(result, Ok(()));
 
-            // Count the votes, dispute ends
-            set_caller::<DefaultEnvironment>(juror_not_assigned[0]);
+            let dispute = bright_disputes
+                .get_dispute(dispute_id)
+                .expect("Failed to get dispute!");
+            assert_eq!(dispute.get_dispute_result(), Some(DisputeResult::Owner));
+            assert_eq!(dispute.abstained_juries(), vec![assigned_juries[0]]);
+            assert_eq!(dispute.banned(), vec![]);
+            assert_eq!(
+                bright_disputes.get_juror_stats(assigned_juries[0]).bans(),
+                0
+            );
+
+            let payouts: Vec<(AccountId, Balance)> = ink::env::test::recorded_events()
+                .flat_map(|event| <Event as Decode>::decode(&mut &event.data[..]))
+                .filter_map(|event| match event {
+                    Event::DepositPaid(event) => Some((event.account_id, event.amount)),
+                    _ => None,
+                })
+                .collect();
+            // Deposit: 6 * 10, juror who abstained gets a half share.
+            assert_eq!(
+                payouts,
+                vec![
+                    (judge_id, 17),
+                    (assigned_juries[0], 8),
+                    (assigned_juries[1], 17),
+                    (assigned_juries[2], 17),
+                ]
+            );
+        }
+
+        /// Verdict for one of the parties can't be issued, when all juries abstained.
+        #[cfg(any(feature = "baby-liminal", feature = "mock-verifier"))]
+        #[ink::test]
+        fn private_verdict_without_votes() {
+            register_verifier();
+
+            let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+            let mut bright_disputes = create_test_bright_dispute_with_running_dispute();
+            let (dispute_id, assigned_juries, judge_id) =
+                create_private_dispute_in_voting_state(&mut bright_disputes);
+
+            for juror_id in &assigned_juries {
+                set_caller::<DefaultEnvironment>(*juror_id);
+                bright_disputes
+                    .abstain(dispute_id)
+                    .expect("Failed to abstain");
+            }
+
+            // Switch state to "CountingTheVotes" state
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let result = bright_disputes.process_dispute_round(dispute_id);
+            assert_eq!(result, Ok(()));
+            let result = bright_disputes.get_verdict_thresholds(dispute_id);
+            assert_eq!(result, Ok((0, 0)));
+
+            // Failed, no votes for the positive or negative verdict.
+            set_caller::<DefaultEnvironment>(judge_id);
+            let hashed_votes = recorded_votes_hash(&bright_disputes, dispute_id);
+            for verdict in [Verdict::Positive, Verdict::Negative] {
+                let result = bright_disputes.issue_the_verdict(
+                    dispute_id,
+                    0,
+                    0,
+                    verdict,
+                    hashed_votes,
+                    vec![],
+                    vec![],
+                    vec![],
+                );
+                assert_eq!(result, Err(BrightDisputesError::MajorityOfVotesNotReached));
+            }
+
+            // Majority is not reached, dispute goes to the next round.
             let result = bright_disputes.issue_the_verdict(
                 dispute_id,
                 0,
                 0,
-                Verdict::Positive,
-                [0u64; 4],
+                Verdict::None,
+                hashed_votes,
+                vec![],
                 vec![],
                 vec![],
             );
             assert_eq!(result, Ok(()));
+            let dispute = bright_disputes
+                .get_dispute(dispute_id)
+                .expect("Failed to get dispute!");
+            assert_eq!(dispute.state(), DisputeState::Running);
+            assert_eq!(dispute.get_dispute_result(), None);
+        }
+
+        /// Verdict in the private voting mode bans juries, who voted against the majority.
+        #[cfg(any(feature = "baby-liminal", feature = "mock-verifier"))]
+        #[ink::test]
+        fn private_verdict_bans_minority() {
+            register_verifier();
+
+            let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+            let mut bright_disputes = BrightDisputes::new();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            set_value_transferred::<DefaultEnvironment>(10);
+            let dispute_id = bright_disputes
+                .create_dispute_with_voting_mode(
+                    "https://brightinventions.pl/".into(),
+                    accounts.bob,
+                    10,
+                    VotingMode::Private,
+                    Some(MajorityThreshold::new(1, 2)),
+                )
+                .expect("Failed to create a dispute!");
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            bright_disputes
+                .confirm_defendant(dispute_id, "".into())
+                .expect("Failed to confirm defendant a dispute!");
+            let (dispute_id, assigned_juries, judge_id) =
+                create_private_dispute_in_voting_state(&mut bright_disputes);
+
+            for (i, juror_id) in assigned_juries.iter().enumerate() {
+                set_caller::<DefaultEnvironment>(*juror_id);
+                bright_disputes
+                    .vote(dispute_id, [i as u64; 4], [i as u64; 4], vec![])
+                    .expect("Failed to vote");
+            }
+
+            // Switch state to "CountingTheVotes" state
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let result = bright_disputes.process_dispute_round(dispute_id);
+            assert_eq!(result, Ok(()));
+
+            // Two of three votes are enough for the majority.
+            let result = bright_disputes.get_verdict_thresholds(dispute_id);
+            assert_eq!(result, Ok((2, 1)));
+
+            set_caller::<DefaultEnvironment>(judge_id);
+            let hashed_votes = recorded_votes_hash(&bright_disputes, dispute_id);
+            let issue_the_verdict = |bright_disputes: &mut BrightDisputes, banned| {
+                bright_disputes.issue_the_verdict(
+                    dispute_id,
+                    1,
+                    2,
+                    Verdict::Positive,
+                    hashed_votes,
+                    vec![],
+                    banned,
+                    vec![],
+                )
+            };
+
+            // Failed, account which is not a juror of the dispute can't be banned.
+            let result = issue_the_verdict(&mut bright_disputes, vec![accounts.alice]);
+            assert_eq!(result, Err(BrightDisputesError::InvalidJurorsBanned));
+
+            // Failed, juror can't be banned twice.
+            let banned = vec![assigned_juries[2], assigned_juries[2]];
+            let result = issue_the_verdict(&mut bright_disputes, banned);
+            assert_eq!(result, Err(BrightDisputesError::InvalidJurorsBanned));
+
+            // Failed, remaining votes don't reach the majority.
+            let banned = vec![assigned_juries[1], assigned_juries[2]];
+            let result = issue_the_verdict(&mut bright_disputes, banned);
+            assert_eq!(result, Err(BrightDisputesError::InvalidJurorsBanned));
+
+            // Last juror voted against the majority.
+            let result = issue_the_verdict(&mut bright_disputes, vec![assigned_juries[2]]);
+            assert_eq!(result, Ok(()));
+
+            let dispute = bright_disputes
+                .get_dispute(dispute_id)
+                .expect("Failed to get dispute!");
+            assert_eq!(dispute.get_dispute_result(), Some(DisputeResult::Owner));
+            assert_eq!(dispute.banned(), vec![assigned_juries[2]]);
+
+            for juror_id in &assigned_juries[..2] {
+                let stats = bright_disputes.get_juror_stats(*juror_id);
+                assert_eq!(stats.votes_with_majority(), 1);
+                assert_eq!(stats.bans(), 0);
+            }
+            let stats = bright_disputes.get_juror_stats(assigned_juries[2]);
+            assert_eq!(stats.votes_with_majority(), 0);
+            assert_eq!(stats.bans(), 1);
         }
 
         // Check switching to next rounds.
+        #[cfg(any(feature = "baby-liminal", feature = "mock-verifier"))]
         #[ink::test]
         fn process_dispute_round_next_round() {
-            mock::register_chain_extensions(());
+            register_verifier();
 
             let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
             set_caller::<DefaultEnvironment>(accounts.alice);
@@ -1365,14 +3996,29 @@ pub mod bright_disputes {
             let result = bright_disputes.process_dispute_round(dispute_id);
             assert_eq!(result, Ok(()));
 
-            // Majority of votes not reached, new round.
+            // Majority of votes not reached, judge can't ban anybody.
             set_caller::<DefaultEnvironment>(juror_not_assigned[0]);
+            let hashed_votes = recorded_votes_hash(&bright_disputes, dispute_id);
             let result = bright_disputes.issue_the_verdict(
                 dispute_id,
                 0,
+                3,
+                Verdict::None,
+                hashed_votes,
+                vec![],
+                vec![assigned_juries[0]],
+                vec![],
+            );
+            assert_eq!(result, Err(BrightDisputesError::InvalidJurorsBanned));
+
+            // Majority of votes not reached, new round.
+            let result = bright_disputes.issue_the_verdict(
+                dispute_id,
                 0,
+                3,
                 Verdict::None,
-                [0u64; 4],
+                hashed_votes,
+                vec![],
                 vec![],
                 vec![],
             );
@@ -1383,5 +4029,158 @@ pub mod bright_disputes {
             let result = bright_disputes.process_dispute_round(dispute_id);
             assert_eq!(result, Err(BrightDisputesError::JuriesPoolIsToSmall));
         }
+
+        // Check juror reputation after the dispute ends.
+        #[cfg(any(feature = "baby-liminal", feature = "mock-verifier"))]
+        #[ink::test]
+        fn juror_stats() {
+            register_verifier();
+
+            let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+
+            let mut bright_disputes = create_test_bright_dispute_with_running_dispute();
+            let dispute_id = 1;
+
+            // Register charlie, eve, frank  and django as a juries.
+            register_valid_juries(&mut bright_disputes);
+            assert_eq!(
+                bright_disputes.get_juror_stats(accounts.charlie),
+                JurorStats::default()
+            );
+
+            // Switch to "PickingJuriesAndJudge" state.
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            bright_disputes
+                .process_dispute_round(dispute_id)
+                .expect("Failed to process dispute round!");
+
+            let dispute = bright_disputes
+                .get_dispute(dispute_id)
+                .expect("Failed to get dispute!");
+            let assigned_juries = dispute.juries();
+            let judge = dispute.judge().expect("Judge was not assigned!");
+
+            // Confirm juries and judge participation
+            set_value_transferred::<DefaultEnvironment>(10);
+            for juror in &assigned_juries {
+                set_caller::<DefaultEnvironment>(*juror);
+                bright_disputes
+                    .confirm_juror_participation_in_dispute(dispute_id, vec![])
+                    .expect("Failed confirm juries participation!");
+            }
+            set_caller::<DefaultEnvironment>(judge);
+            bright_disputes
+                .confirm_judge_participation_in_dispute(dispute_id, vec![])
+                .expect("Failed to confirm judge participation!");
+
+            // Switch state to "Voting" state
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            bright_disputes
+                .process_dispute_round(dispute_id)
+                .expect("Failed to process dispute round!");
+
+            // Juries voting
+            for juror in &assigned_juries {
+                set_caller::<DefaultEnvironment>(*juror);
+                bright_disputes
+                    .vote(dispute_id, [0u64; 4], [0u64; 4], vec![])
+                    .expect("Failed to vote");
+            }
+
+            // Switch state to "CountingTheVotes" state
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            bright_disputes
+                .process_dispute_round(dispute_id)
+                .expect("Failed to process dispute round!");
+
+            // Count the votes, all juries voted with the majority.
+            set_caller::<DefaultEnvironment>(judge);
+            let hashed_votes = recorded_votes_hash(&bright_disputes, dispute_id);
+            bright_disputes
+                .issue_the_verdict(
+                    dispute_id,
+                    0,
+                    3,
+                    Verdict::Positive,
+                    hashed_votes,
+                    vec![],
+                    vec![],
+                    vec![],
+                )
+                .expect("Failed to issue the verdict!");
+
+            for juror in &assigned_juries {
+                let stats = bright_disputes.get_juror_stats(*juror);
+                assert_eq!(stats.cases_served(), 1);
+                assert_eq!(stats.votes_with_majority(), 1);
+                assert_eq!(stats.bans(), 0);
+                assert_eq!(stats.missed_deadlines(), 0);
+            }
+
+            let stats = bright_disputes.get_juror_stats(judge);
+            assert_eq!(stats.cases_served(), 1);
+            assert_eq!(stats.votes_with_majority(), 0);
+            assert_eq!(stats.bans(), 0);
+        }
+
+        // Check juror reputation, when juror is banned for the vote against the majority.
+        #[ink::test]
+        fn juror_stats_banned_by_verdict() {
+            let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+            let mut bright_disputes = BrightDisputes::new();
+            let (dispute_id, assigned_juries) = create_commit_reveal_dispute_in_voting_state(
+                &mut bright_disputes,
+                Some(MajorityThreshold::new(1, 2)),
+            );
+
+            // Two of three votes are enough for the majority.
+            let salt = [3u8; 32];
+            let votes = [1, 1, 0];
+            for (juror_id, vote) in assigned_juries.iter().zip(votes) {
+                set_caller::<DefaultEnvironment>(*juror_id);
+                bright_disputes
+                    .commit_vote(dispute_id, Vote::commitment(*juror_id, vote, salt))
+                    .expect("Failed to commit the vote");
+            }
+
+            // Switch state to "Revealing" state
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            bright_disputes
+                .process_dispute_round(dispute_id)
+                .expect("Failed to process dispute round!");
+
+            for (juror_id, vote) in assigned_juries.iter().zip(votes) {
+                set_caller::<DefaultEnvironment>(*juror_id);
+                bright_disputes
+                    .reveal_vote(dispute_id, vote, salt)
+                    .expect("Failed to reveal the vote");
+            }
+
+            // Votes are counted by the contract, juror who voted against the majority is banned.
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            bright_disputes
+                .process_dispute_round(dispute_id)
+                .expect("Failed to process dispute round!");
+
+            let dispute = bright_disputes
+                .get_dispute(dispute_id)
+                .expect("Failed to get dispute!");
+            assert_eq!(dispute.get_dispute_result(), Some(DisputeResult::Owner));
+            assert_eq!(dispute.banned(), vec![assigned_juries[2]]);
+
+            for juror in &assigned_juries[..2] {
+                let stats = bright_disputes.get_juror_stats(*juror);
+                assert_eq!(stats.cases_served(), 1);
+                assert_eq!(stats.votes_with_majority(), 1);
+                assert_eq!(stats.bans(), 0);
+                assert_eq!(stats.missed_deadlines(), 0);
+            }
+
+            let stats = bright_disputes.get_juror_stats(assigned_juries[2]);
+            assert_eq!(stats.cases_served(), 0);
+            assert_eq!(stats.votes_with_majority(), 0);
+            assert_eq!(stats.bans(), 1);
+        }
     }
 }
@@ -33,6 +33,173 @@ pub enum DisputeResult {
     Defendant,
 }
 
+/// Voting mode of the dispute. In the `Private` mode votes are encrypted for
+/// the judge and verified with zero-knowledge proofs. In the `CommitReveal`
+/// mode juries commit hashes of their votes, reveal them in the `Revealing`
+/// round and votes are counted on-chain, so no judge is assigned.
+#[derive(Clone, Debug, Default, PartialEq, scale::Decode, scale::Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+)]
+pub enum VotingMode {
+    #[default]
+    Private,
+    CommitReveal,
+}
+
+/// Part of the votes needed to reach the majority: `numerator / denominator`.
+#[derive(Clone, Debug, PartialEq, scale::Decode, scale::Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+)]
+pub struct MajorityThreshold {
+    numerator: u32,
+    denominator: u32,
+}
+
+impl Default for MajorityThreshold {
+    /// 70% of the votes.
+    fn default() -> Self {
+        MajorityThreshold {
+            numerator: 7,
+            denominator: 10,
+        }
+    }
+}
+
+impl MajorityThreshold {
+    pub fn new(numerator: u32, denominator: u32) -> Self {
+        MajorityThreshold {
+            numerator,
+            denominator,
+        }
+    }
+
+    /// Assert if threshold is not a part of the votes: `0 < numerator <= denominator`.
+    pub fn assert_valid(&self) -> Result<()> {
+        if self.numerator == 0 || self.numerator > self.denominator {
+            return Err(BrightDisputesError::InvalidMajorityThreshold);
+        }
+        Ok(())
+    }
+
+    /// Minimum number of positive votes, which gives the positive verdict. It is computed
+    /// in `u64`, so the product of the numerator and the number of votes doesn't overflow.
+    pub fn votes_minimum(&self, number_of_votes: u32) -> u32 {
+        let numerator = self.numerator as u64 * number_of_votes as u64;
+        let denominator = self.denominator as u64;
+        ((numerator + denominator - 1) / denominator) as u32
+    }
+
+    /// Maximum number of positive votes, which gives the negative verdict.
+    pub fn votes_maximum(&self, number_of_votes: u32) -> u32 {
+        number_of_votes - self.votes_minimum(number_of_votes)
+    }
+}
+
+/// Settlement proposed by one of the dispute parties. `owner_share` is the
+/// percentage of the parties funds, which goes to the owner.
+#[derive(Clone, Debug, PartialEq, scale::Decode, scale::Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+)]
+pub struct Settlement {
+    proposed_by: AccountId,
+    owner_share: u8,
+}
+
+impl Settlement {
+    /// Get account which proposed the settlement
+    pub fn proposed_by(&self) -> AccountId {
+        self.proposed_by
+    }
+
+    /// Get percentage of the parties funds, which goes to the owner
+    pub fn owner_share(&self) -> u8 {
+        self.owner_share
+    }
+}
+
+/// Rules of issuing the default judgment, when the defendant doesn't confirm
+/// the dispute within `response_time`. If `max_escrow` is set, only disputes
+/// with escrow up to this value can be judged by default.
+#[derive(Clone, Debug, PartialEq, scale::Decode, scale::Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+)]
+pub struct DefaultJudgmentRules {
+    pub response_time: Timestamp,
+    pub max_escrow: Option<Balance>,
+}
+
+impl DefaultJudgmentRules {
+    /// Assert if default judgment can not be issued for the dispute. It is issued
+    /// only when nobody but the owner has deposited, so there is nothing to refund.
+    pub fn assert_allowed(&self, dispute: &Dispute, now: Timestamp) -> Result<()> {
+        if dispute.state != DisputeState::Created || dispute.deposit != dispute.escrow {
+            return Err(BrightDisputesError::InvalidDisputeState);
+        }
+        if let Some(max_escrow) = self.max_escrow {
+            if dispute.escrow > max_escrow {
+                return Err(BrightDisputesError::DefaultJudgmentNotAllowed);
+            }
+        }
+        if now < dispute.response_deadline(self.response_time) {
+            return Err(BrightDisputesError::ResponseDeadlineNotReached);
+        }
+        Ok(())
+    }
+}
+
+/// Filter used to query disputes, all set conditions need to be met.
+#[derive(Clone, Debug, Default, PartialEq, scale::Decode, scale::Encode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct DisputeFilter {
+    pub state: Option<DisputeState>,
+    pub owner: Option<AccountId>,
+    pub defendant: Option<AccountId>,
+    pub juror: Option<AccountId>,
+}
+
+impl DisputeFilter {
+    /// Get accounts, which disputes are filtered by.
+    pub fn accounts(&self) -> Vec<AccountId> {
+        [self.owner, self.defendant, self.juror]
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+
+    /// Check if dispute meets the filter conditions.
+    pub fn matches(&self, dispute: &Dispute) -> bool {
+        if let Some(state) = &self.state {
+            if dispute.state != *state {
+                return false;
+            }
+        }
+        if let Some(owner) = self.owner {
+            if dispute.owner != owner {
+                return false;
+            }
+        }
+        if let Some(defendant) = self.defendant {
+            if dispute.defendant != defendant {
+                return false;
+            }
+        }
+        if let Some(juror) = self.juror {
+            if !dispute.has_participated(juror) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, scale::Decode, scale::Encode)]
 #[cfg_attr(
     feature = "std",
@@ -45,6 +212,7 @@ pub struct Dispute {
     owner_link: String,
     escrow: Balance,
     deposit: Balance,
+    created_at: Timestamp,
 
     defendant: AccountId,
     defendant_link: Option<String>,
@@ -57,6 +225,10 @@ pub struct Dispute {
     banned: Vec<AccountId>,
     votes: Vec<Vote>,
     votes_hash: VoteHash,
+    settlement: Option<Settlement>,
+    voting_mode: VotingMode,
+    revealed_votes: Vec<(AccountId, u8)>,
+    majority_threshold: MajorityThreshold,
 }
 
 impl Dispute {
@@ -77,6 +249,7 @@ impl Dispute {
             owner_link,
             escrow,
             deposit: escrow,
+            created_at: ink::env::block_timestamp::<ink::env::DefaultEnvironment>(),
             defendant,
             defendant_link: None,
             dispute_result: None,
@@ -87,6 +260,10 @@ impl Dispute {
             banned: Vec::new(),
             votes: Vec::new(),
             votes_hash: [1u64; 4],
+            settlement: None,
+            voting_mode: VotingMode::default(),
+            revealed_votes: Vec::new(),
+            majority_threshold: MajorityThreshold::default(),
         }
     }
 
@@ -105,6 +282,66 @@ impl Dispute {
         return self.defendant;
     }
 
+    /// Get dispute state
+    pub fn state(&self) -> DisputeState {
+        return self.state.clone();
+    }
+
+    /// Get dispute creation time
+    pub fn created_at(&self) -> Timestamp {
+        return self.created_at;
+    }
+
+    /// Get deadline for the defendant to confirm the dispute
+    pub fn response_deadline(&self, response_time: Timestamp) -> Timestamp {
+        return self.created_at.saturating_add(response_time);
+    }
+
+    /// Get dispute voting mode
+    pub fn voting_mode(&self) -> VotingMode {
+        return self.voting_mode.clone();
+    }
+
+    /// Set dispute voting mode, it can be done only before the dispute starts.
+    pub fn set_voting_mode(&mut self, voting_mode: VotingMode) -> Result<()> {
+        self.assert_state(DisputeState::Created)?;
+        self.voting_mode = voting_mode;
+        Ok(())
+    }
+
+    /// Get votes revealed by the juries (commit-reveal voting mode). In the private
+    /// voting mode only the abstain votes are public.
+    pub fn revealed_votes(&self) -> Vec<(AccountId, u8)> {
+        return self.revealed_votes.clone();
+    }
+
+    /// Get the number of votes counted to the majority, juries who abstained are not
+    /// counted. In the commit-reveal voting mode only the revealed votes are counted.
+    pub fn number_of_votes(&self) -> u32 {
+        match self.voting_mode {
+            VotingMode::Private => self.votes.len() as u32,
+            VotingMode::CommitReveal => self
+                .revealed_votes
+                .iter()
+                .filter(|(_, vote)| *vote != Vote::ABSTAIN)
+                .count() as u32,
+        }
+    }
+
+    /// Get part of the votes needed to reach the majority
+    pub fn majority_threshold(&self) -> MajorityThreshold {
+        return self.majority_threshold.clone();
+    }
+
+    /// Set part of the votes needed to reach the majority, it can be done only
+    /// before the dispute starts.
+    pub fn set_majority_threshold(&mut self, majority_threshold: MajorityThreshold) -> Result<()> {
+        self.assert_state(DisputeState::Created)?;
+        majority_threshold.assert_valid()?;
+        self.majority_threshold = majority_threshold;
+        Ok(())
+    }
+
     /// Get dispute escrow
     pub fn escrow(&self) -> Balance {
         return self.escrow;
@@ -208,6 +445,52 @@ impl Dispute {
         Ok(())
     }
 
+    /// End the dispute in favour of the owner, when the defendant ignores it.
+    pub fn issue_default_judgment(&mut self) -> Result<()> {
+        self.assert_owner_call()?;
+        self.assert_state(DisputeState::Created)?;
+        self.state = DisputeState::Ended;
+        self.dispute_result = Some(DisputeResult::Owner);
+        Ok(())
+    }
+
+    /// Propose the settlement of the dispute, it can be done by the owner or
+    /// the defendant in any non-final state. New proposal replaces the previous one.
+    pub fn propose_settlement(&mut self, owner_share: u8) -> Result<()> {
+        self.assert_owner_or_defendant_call()?;
+        self.assert_can_be_settled()?;
+        if owner_share > 100 {
+            return Err(BrightDisputesError::InvalidSettlementSplit);
+        }
+        self.settlement = Some(Settlement {
+            proposed_by: ink::env::caller::<ink::env::DefaultEnvironment>(),
+            owner_share,
+        });
+        Ok(())
+    }
+
+    /// Accept the settlement proposed by the other party, it closes the dispute.
+    pub fn accept_settlement(&mut self) -> Result<Settlement> {
+        self.assert_owner_or_defendant_call()?;
+        self.assert_can_be_settled()?;
+        let settlement = self
+            .settlement
+            .clone()
+            .ok_or(BrightDisputesError::SettlementNotProposed)?;
+        if settlement.proposed_by == ink::env::caller::<ink::env::DefaultEnvironment>() {
+            return Err(BrightDisputesError::NotAuthorized);
+        }
+
+        self.state = DisputeState::Closed;
+        self.dispute_round = None;
+        Ok(settlement)
+    }
+
+    /// Get proposed settlement
+    pub fn settlement(&self) -> Option<Settlement> {
+        return self.settlement.clone();
+    }
+
     /// Confirm defendant participation in dispute
     pub fn confirm_defendant(&mut self, defendant_link: String) -> Result<()> {
         self.assert_defendant_call()?;
@@ -217,18 +500,125 @@ impl Dispute {
         Ok(())
     }
 
-    /// Make a vote
+    /// Make a vote. The next votes of the juror go through `change_vote`.
     pub fn vote(&mut self, vote: Vote, hash_of_all_votes: VoteHash) -> Result<()> {
+        self.assert_voting_mode(VotingMode::Private)?;
         self.assert_state(DisputeState::Running)?;
         self.assert_can_vote()?;
         self.assert_juror(vote.juror())?;
         self.assert_not_voted(vote.juror())?;
-        self.votes.push(vote.clone());
+        self.votes.push(vote);
+        self.votes_hash = hash_of_all_votes;
+
+        Ok(())
+    }
+
+    /// Abstain from the vote (private voting mode), instead of voting. The abstain vote is
+    /// public, because the vote relation constrains the vote to `0` or `1`.
+    pub fn abstain(&mut self, juror: AccountId) -> Result<()> {
+        self.assert_voting_mode(VotingMode::Private)?;
+        self.assert_state(DisputeState::Running)?;
+        self.assert_can_vote()?;
+        self.assert_juror(juror)?;
+        self.assert_not_voted(juror)?;
+        self.revealed_votes.push((juror, Vote::ABSTAIN));
+        Ok(())
+    }
+
+    /// Change the juror vote (private voting mode) until the voting ends. The new vote
+    /// replaces the previous one and `votes_hash` chains it, so the next vote proof is
+    /// made for it. The verdict is proven only for the replaced votes.
+    pub fn change_vote(&mut self, vote: Vote, hash_of_all_votes: VoteHash) -> Result<()> {
+        self.assert_voting_mode(VotingMode::Private)?;
+        self.assert_state(DisputeState::Running)?;
+        self.assert_can_vote()?;
+        self.assert_juror(vote.juror())?;
+        let previous = self
+            .votes
+            .iter()
+            .find(|v| v.juror() == vote.juror())
+            .ok_or(BrightDisputesError::InvalidAction)?;
+        if vote.public_key().is_none() || previous.public_key() == vote.public_key() {
+            return Err(BrightDisputesError::VotePublicKeyReused);
+        }
+        self.set_vote(vote);
         self.votes_hash = hash_of_all_votes;
 
         Ok(())
     }
 
+    /// Commit the hash of the juror vote (commit-reveal voting mode). Juror can
+    /// commit again until the voting ends, the latest commitment is revealed.
+    pub fn commit_vote(&mut self, vote: Vote) -> Result<()> {
+        self.assert_voting_mode(VotingMode::CommitReveal)?;
+        self.assert_state(DisputeState::Running)?;
+        self.assert_can_vote()?;
+        self.assert_juror(vote.juror())?;
+        self.set_vote(vote);
+        Ok(())
+    }
+
+    /// Check if juror has voted in the current dispute round.
+    pub fn has_voted(&self, juror: AccountId) -> bool {
+        self.votes.iter().any(|v| v.juror() == juror)
+    }
+
+    /// Check if jurors can be banned by the verdict: each of them is assigned to
+    /// the dispute, voted and is listed once.
+    pub fn assert_jurors_banned(&self, jurors_banned: &[AccountId]) -> Result<()> {
+        let juries = self.juries();
+        for (i, juror_id) in jurors_banned.iter().enumerate() {
+            if !juries.contains(juror_id)
+                || !self.has_voted(*juror_id)
+                || jurors_banned[..i].contains(juror_id)
+            {
+                return Err(BrightDisputesError::InvalidJurorsBanned);
+            }
+        }
+        Ok(())
+    }
+
+    /// Reveal the juror vote, it has to match the committed hash (commit-reveal voting mode)
+    pub fn reveal_vote(&mut self, juror: AccountId, vote: u8, salt: [u8; 32]) -> Result<()> {
+        self.assert_voting_mode(VotingMode::CommitReveal)?;
+        self.assert_state(DisputeState::Running)?;
+        self.assert_can_reveal()?;
+        self.assert_juror(juror)?;
+        if self.revealed_votes.iter().any(|(id, _)| *id == juror) {
+            return Err(BrightDisputesError::JurorAlreadyVoted);
+        }
+
+        let commitment = self
+            .votes
+            .iter()
+            .find(|v| v.juror() == juror)
+            .ok_or(BrightDisputesError::InvalidVoteReveal)?
+            .vote();
+        if vote > Vote::ABSTAIN || Vote::commitment(juror, vote, salt) != commitment {
+            return Err(BrightDisputesError::InvalidVoteReveal);
+        }
+        self.revealed_votes.push((juror, vote));
+        Ok(())
+    }
+
+    /// Get juries who abstained from the vote.
+    pub fn abstained_juries(&self) -> Vec<AccountId> {
+        self.revealed_votes
+            .iter()
+            .filter(|(_, vote)| *vote == Vote::ABSTAIN)
+            .map(|(juror, _)| *juror)
+            .collect()
+    }
+
+    /// Get juries who have not revealed their votes.
+    pub fn get_not_revealed_juries(&self) -> Vec<AccountId> {
+        self.juries()
+            .iter()
+            .filter(|&id| !self.revealed_votes.iter().any(|(juror, _)| juror == id))
+            .map(|&id| id)
+            .collect()
+    }
+
     /// Assign juror to the dispute
     pub fn assign_juror(&mut self, juror: &mut Juror) -> Result<()> {
         self.assert_state(DisputeState::Running)?;
@@ -277,14 +667,20 @@ impl Dispute {
         // Clear votes.
         self.votes.clear();
         self.votes_hash = [1u64; 4];
+        self.revealed_votes.clear();
 
         // Set new dispute round.
         self.dispute_round = Some(DisputeRound::create(timestamp, None));
         Ok(())
     }
 
-    /// Start new dispute round
-    pub fn next_dispute_round(&mut self, timestamp: Timestamp) -> Result<()> {
+    /// Start new dispute round, `max_juries` limits the number of juries,
+    /// e.g. to the number of votes, which the verdict proof can count.
+    pub fn next_dispute_round(
+        &mut self,
+        timestamp: Timestamp,
+        max_juries: Option<u8>,
+    ) -> Result<()> {
         self.assert_state(DisputeState::Running)?;
         if self.dispute_round_counter >= Dispute::MAX_DISPUTE_ROUNDS {
             return Err(BrightDisputesError::DisputeRoundLimitReached);
@@ -293,9 +689,14 @@ impl Dispute {
         // Clear votes.
         self.votes.clear();
         self.votes_hash = [1u64; 4];
+        self.revealed_votes.clear();
 
         // Increase the number of juries for the next round.
-        let number_of_juries: u8 = self.juries.len() as u8 + Dispute::INCREMENT_JURIES_BY;
+        let mut number_of_juries: u8 =
+            (self.juries.len() as u8).saturating_add(Dispute::INCREMENT_JURIES_BY);
+        if let Some(max_juries) = max_juries {
+            number_of_juries = number_of_juries.min(max_juries);
+        }
 
         // Set new dispute round
         self.dispute_round = Some(DisputeRound::create(timestamp, Some(number_of_juries)));
@@ -319,11 +720,19 @@ impl Dispute {
         Err(BrightDisputesError::DisputeRoundNotStarted)
     }
 
+    /// Check if account is or was a juror / judge of the dispute.
+    pub fn has_participated(&self, account_id: AccountId) -> bool {
+        self.judge == Some(account_id)
+            || self.juries.contains(&account_id)
+            || self.banned.contains(&account_id)
+    }
+
     /// Get juries who have not voted.
     pub fn get_not_voted_juries(&self) -> Vec<AccountId> {
         self.juries()
             .iter()
             .filter(|&id| self.votes().iter().position(|v| v.juror() == *id).is_none())
+            .filter(|id| !self.abstained_juries().contains(id))
             .map(|&id| id)
             .collect()
     }
@@ -336,6 +745,15 @@ impl Dispute {
         Ok(())
     }
 
+    /// Assert if call is done neither by the owner nor by the defendant
+    pub fn assert_owner_or_defendant_call(&self) -> Result<()> {
+        let caller = ink::env::caller::<ink::env::DefaultEnvironment>();
+        if self.owner != caller && self.defendant != caller {
+            return Err(BrightDisputesError::NotAuthorized);
+        }
+        Ok(())
+    }
+
     /// Assert if dispute is not ended.
     pub fn assert_dispute_ended(&self) -> Result<()> {
         if self.state != DisputeState::Ended
@@ -404,6 +822,21 @@ impl Dispute {
         Ok(())
     }
 
+    fn assert_can_be_settled(&self) -> Result<()> {
+        if self.state != DisputeState::Created && self.state != DisputeState::Running {
+            return Err(BrightDisputesError::InvalidDisputeState);
+        }
+        Ok(())
+    }
+
+    /// Assert if evidence can not be submitted, dispute is neither created nor running.
+    pub fn assert_can_submit_evidence(&self) -> Result<()> {
+        if self.state != DisputeState::Created && self.state != DisputeState::Running {
+            return Err(BrightDisputesError::InvalidDisputeState);
+        }
+        Ok(())
+    }
+
     fn assert_state(&self, state: DisputeState) -> Result<()> {
         if self.state != state {
             return Err(BrightDisputesError::InvalidDisputeState);
@@ -421,12 +854,34 @@ impl Dispute {
     }
 
     fn assert_not_voted(&self, juror: AccountId) -> Result<()> {
-        for v in &self.votes {
-            if v.juror() == juror {
-                return Err(BrightDisputesError::JurorAlreadyVoted);
-            }
+        if self.has_voted(juror) || self.abstained_juries().contains(&juror) {
+            return Err(BrightDisputesError::JurorAlreadyVoted);
+        }
+        Ok(())
+    }
+
+    /// Add the juror vote or replace the previous one.
+    fn set_vote(&mut self, vote: Vote) {
+        match self.votes.iter_mut().find(|v| v.juror() == vote.juror()) {
+            Some(previous) => *previous = vote,
+            None => self.votes.push(vote),
         }
-        return Ok(());
+    }
+
+    /// Assert if dispute is not in the voting mode.
+    pub fn assert_voting_mode(&self, voting_mode: VotingMode) -> Result<()> {
+        if self.voting_mode != voting_mode {
+            return Err(BrightDisputesError::InvalidVotingMode);
+        }
+        Ok(())
+    }
+
+    fn assert_can_reveal(&self) -> Result<()> {
+        if let Some(round) = &self.dispute_round {
+            round.assert_if_not_revealing_time()?;
+            return Ok(());
+        }
+        Err(BrightDisputesError::WrongDisputeRoundState)
     }
 
     fn assert_can_vote(&self) -> Result<()> {
@@ -511,9 +966,142 @@ mod tests {
         let result = dispute.vote(Vote::create(accounts.charlie, [0u64; 4]), [0u64; 4]);
         assert_eq!(result, Ok(()));
 
-        // Juror can vote only once
+        // Failed, vote is changed only with `change_vote`.
+        let result = dispute.vote(Vote::create(accounts.charlie, [1u64; 4]), [2u64; 4]);
+        assert_eq!(result, Err(BrightDisputesError::JurorAlreadyVoted));
+        assert_eq!(
+            dispute.votes(),
+            vec![Vote::create(accounts.charlie, [0u64; 4])]
+        );
+        assert_eq!(dispute.votes_hash(), [0u64; 4]);
+
+        // Failed, juror who hasn't voted can't change the vote.
+        let mut juror = Juror::create(accounts.eve);
+        dispute
+            .assign_juror(&mut juror)
+            .expect("Unable to add juror!");
+        let vote = Vote::create_with_public_key(accounts.eve, [1u64; 4], vec![1]);
+        let result = dispute.change_vote(vote, [2u64; 4]);
+        assert_eq!(result, Err(BrightDisputesError::InvalidAction));
+
+        // Failed, changed vote has to be encrypted with a fresh key.
+        let vote = Vote::create(accounts.charlie, [1u64; 4]);
+        let result = dispute.change_vote(vote, [2u64; 4]);
+        assert_eq!(result, Err(BrightDisputesError::VotePublicKeyReused));
+
+        // Success, changed vote replaces the previous one.
+        let vote = Vote::create_with_public_key(accounts.charlie, [1u64; 4], vec![1]);
+        let result = dispute.change_vote(vote.clone(), [2u64; 4]);
+        assert_eq!(result, Ok(()));
+        assert_eq!(dispute.votes(), vec![vote]);
+        assert_eq!(dispute.votes_hash(), [2u64; 4]);
+
+        // Failed, key of the previous vote can't be used again.
+        let vote = Vote::create_with_public_key(accounts.charlie, [0u64; 4], vec![1]);
+        let result = dispute.change_vote(vote, [3u64; 4]);
+        assert_eq!(result, Err(BrightDisputesError::VotePublicKeyReused));
+
+        // Juror can't vote after the voting ends.
+        dispute.dispute_round = Some(DisputeRoundFake::revealing(0u64));
+        let result = dispute.vote(Vote::create(accounts.charlie, [0u64; 4]), [0u64; 4]);
+        assert_eq!(result, Err(BrightDisputesError::WrongDisputeRoundState));
+        let vote = Vote::create_with_public_key(accounts.charlie, [0u64; 4], vec![2]);
+        let result = dispute.change_vote(vote, [3u64; 4]);
+        assert_eq!(result, Err(BrightDisputesError::WrongDisputeRoundState));
+    }
+
+    #[ink::test]
+    fn abstain() {
+        let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+        let mut dispute = default_test_running_dispute();
+
+        for juror_id in [accounts.charlie, accounts.eve] {
+            let mut juror = Juror::create(juror_id);
+            dispute
+                .assign_juror(&mut juror)
+                .expect("Unable to add juror!");
+        }
+
+        // Force "Voting" state
+        dispute.dispute_round = Some(DisputeRoundFake::voting(0u64));
+
+        // Only juror can abstain
+        let result = dispute.abstain(accounts.bob);
+        assert_eq!(result, Err(BrightDisputesError::NotAuthorized));
+
+        // Success, juror who abstained has no vote, but has acted.
+        let result = dispute.abstain(accounts.charlie);
+        assert_eq!(result, Ok(()));
+        assert_eq!(dispute.abstained_juries(), vec![accounts.charlie]);
+        assert_eq!(dispute.votes(), vec![]);
+        assert_eq!(dispute.get_not_voted_juries(), vec![accounts.eve]);
+
+        // Failed, juror can't vote or abstain again.
+        let result = dispute.abstain(accounts.charlie);
+        assert_eq!(result, Err(BrightDisputesError::JurorAlreadyVoted));
         let result = dispute.vote(Vote::create(accounts.charlie, [0u64; 4]), [0u64; 4]);
         assert_eq!(result, Err(BrightDisputesError::JurorAlreadyVoted));
+
+        // Failed, juror who has voted can't abstain.
+        dispute
+            .vote(Vote::create(accounts.eve, [0u64; 4]), [0u64; 4])
+            .expect("Failed make a vote!");
+        let result = dispute.abstain(accounts.eve);
+        assert_eq!(result, Err(BrightDisputesError::JurorAlreadyVoted));
+
+        // Abstain in the commit-reveal voting mode is revealed.
+        dispute.voting_mode = VotingMode::CommitReveal;
+        let result = dispute.abstain(accounts.eve);
+        assert_eq!(result, Err(BrightDisputesError::InvalidVotingMode));
+    }
+
+    #[ink::test]
+    fn commit_and_reveal_vote() {
+        let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+        let mut dispute = default_test_running_dispute();
+        dispute.voting_mode = VotingMode::CommitReveal;
+
+        let mut juror = Juror::create(accounts.charlie);
+        dispute
+            .assign_juror(&mut juror)
+            .expect("Unable to add juror!");
+        let salt = [7u8; 32];
+        let commitment = Vote::commitment(accounts.charlie, 1, salt);
+
+        // Force "Voting" state
+        dispute.dispute_round = Some(DisputeRoundFake::voting(0u64));
+
+        // Failed, private vote in commit-reveal mode.
+        let result = dispute.vote(Vote::create(accounts.charlie, commitment), [0u64; 4]);
+        assert_eq!(result, Err(BrightDisputesError::InvalidVotingMode));
+
+        // Success
+        let result = dispute.commit_vote(Vote::create(accounts.charlie, commitment));
+        assert_eq!(result, Ok(()));
+
+        // Failed, it's not time to reveal.
+        let result = dispute.reveal_vote(accounts.charlie, 1, salt);
+        assert_eq!(result, Err(BrightDisputesError::WrongDisputeRoundState));
+
+        // Force "Revealing" state
+        dispute.dispute_round = Some(DisputeRoundFake::revealing(0u64));
+        assert_eq!(dispute.get_not_revealed_juries(), vec![accounts.charlie]);
+
+        // Failed, vote doesn't match the commitment.
+        let result = dispute.reveal_vote(accounts.charlie, 0, salt);
+        assert_eq!(result, Err(BrightDisputesError::InvalidVoteReveal));
+        let result = dispute.reveal_vote(accounts.charlie, 1, [0u8; 32]);
+        assert_eq!(result, Err(BrightDisputesError::InvalidVoteReveal));
+
+        // Success
+        let result = dispute.reveal_vote(accounts.charlie, 1, salt);
+        assert_eq!(result, Ok(()));
+        assert_eq!(dispute.revealed_votes(), vec![(accounts.charlie, 1)]);
+        assert_eq!(dispute.get_not_revealed_juries(), vec![]);
+
+        // Juror can reveal only once
+        let result = dispute.reveal_vote(accounts.charlie, 1, salt);
+        assert_eq!(result, Err(BrightDisputesError::JurorAlreadyVoted));
     }
 
     #[ink::test]
@@ -808,10 +1396,10 @@ mod tests {
         let mut dispute = default_test_running_dispute();
 
         for _ in 1..Dispute::MAX_DISPUTE_ROUNDS {
-            let result = dispute.next_dispute_round(0u64);
+            let result = dispute.next_dispute_round(0u64, None);
             assert_eq!(result, Ok(()));
         }
-        let result = dispute.next_dispute_round(0u64);
+        let result = dispute.next_dispute_round(0u64, None);
         assert_eq!(result, Err(BrightDisputesError::DisputeRoundLimitReached));
     }
 
@@ -840,11 +1428,110 @@ mod tests {
         assert_eq!(dispute.juries().len(), 2);
         assert_eq!(dispute.banned().len(), 0);
 
-        let result = dispute.next_dispute_round(0u64);
+        let result = dispute.next_dispute_round(0u64, None);
         assert_eq!(result, Ok(()));
 
         assert_eq!(dispute.votes().len(), 0);
         assert_eq!(dispute.juries().len(), 2);
+        assert_eq!(dispute.dispute_round().unwrap().number_of_juries(), 4);
+    }
+
+    #[ink::test]
+    fn next_dispute_round_max_juries() {
+        let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+        let mut dispute = default_test_running_dispute();
+
+        let mut charlie = Juror::create(accounts.charlie);
+        dispute
+            .assign_juror(&mut charlie)
+            .expect("Unable to add juror!");
+        let mut eve = Juror::create(accounts.eve);
+        dispute
+            .assign_juror(&mut eve)
+            .expect("Unable to add juror!");
+
+        // Number of juries can't exceed the limit.
+        let result = dispute.next_dispute_round(0u64, Some(3));
+        assert_eq!(result, Ok(()));
+        assert_eq!(dispute.dispute_round().unwrap().number_of_juries(), 3);
+
+        let result = dispute.next_dispute_round(0u64, Some(2));
+        assert_eq!(result, Ok(()));
+        assert_eq!(dispute.dispute_round().unwrap().number_of_juries(), 2);
+    }
+
+    #[ink::test]
+    fn majority_threshold() {
+        let threshold = MajorityThreshold::default();
+
+        assert_eq!(threshold.votes_minimum(0), 0);
+        assert_eq!(threshold.votes_maximum(0), 0);
+        assert_eq!(threshold.votes_minimum(3), 3);
+        assert_eq!(threshold.votes_maximum(3), 0);
+        assert_eq!(threshold.votes_minimum(7), 5);
+        assert_eq!(threshold.votes_maximum(7), 2);
+        assert_eq!(threshold.votes_minimum(10), 7);
+        assert_eq!(threshold.votes_maximum(10), 3);
+
+        let threshold = MajorityThreshold::new(1, 2);
+        assert_eq!(threshold.votes_minimum(3), 2);
+        assert_eq!(threshold.votes_maximum(3), 1);
+        assert_eq!(threshold.votes_minimum(4), 2);
+        assert_eq!(threshold.votes_maximum(4), 2);
+
+        // Thresholds at the bounds don't overflow.
+        let threshold = MajorityThreshold::new(u32::MAX, u32::MAX);
+        assert_eq!(threshold.votes_minimum(u32::MAX), u32::MAX);
+        assert_eq!(threshold.votes_maximum(u32::MAX), 0);
+        assert_eq!(threshold.votes_minimum(3), 3);
+        let threshold = MajorityThreshold::new(1, u32::MAX);
+        assert_eq!(threshold.votes_minimum(u32::MAX), 1);
+        assert_eq!(threshold.votes_maximum(u32::MAX), u32::MAX - 1);
+        assert_eq!(threshold.votes_minimum(3), 1);
+
+        let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+        let mut dispute = Dispute::create(1, "".into(), accounts.bob, 10);
+        let result = dispute.set_majority_threshold(MajorityThreshold::new(0, 2));
+        assert_eq!(result, Err(BrightDisputesError::InvalidMajorityThreshold));
+        let result = dispute.set_majority_threshold(MajorityThreshold::new(3, 2));
+        assert_eq!(result, Err(BrightDisputesError::InvalidMajorityThreshold));
+        let result = dispute.set_majority_threshold(MajorityThreshold::new(2, 2));
+        assert_eq!(result, Ok(()));
+        assert_eq!(dispute.majority_threshold(), MajorityThreshold::new(2, 2));
+
+        // Failed, dispute has started.
+        let mut dispute = default_test_running_dispute();
+        let result = dispute.set_majority_threshold(MajorityThreshold::new(1, 2));
+        assert_eq!(result, Err(BrightDisputesError::InvalidDisputeState));
+    }
+
+    #[ink::test]
+    fn assert_jurors_banned() {
+        let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+        let mut dispute = default_test_running_dispute();
+        dispute.juries = vec![accounts.charlie, accounts.eve, accounts.frank];
+        dispute.votes = vec![
+            Vote::create(accounts.charlie, [0u64; 4]),
+            Vote::create(accounts.eve, [0u64; 4]),
+        ];
+
+        // Only juries assigned to the dispute can be banned
+        let result = dispute.assert_jurors_banned(&[accounts.django]);
+        assert_eq!(result, Err(BrightDisputesError::InvalidJurorsBanned));
+
+        // Only juries who voted can be banned
+        let result = dispute.assert_jurors_banned(&[accounts.frank]);
+        assert_eq!(result, Err(BrightDisputesError::InvalidJurorsBanned));
+
+        // Juror can't be banned twice
+        let result = dispute.assert_jurors_banned(&[accounts.charlie, accounts.charlie]);
+        assert_eq!(result, Err(BrightDisputesError::InvalidJurorsBanned));
+
+        // Success
+        let result = dispute.assert_jurors_banned(&[accounts.eve]);
+        assert_eq!(result, Ok(()));
+        let result = dispute.assert_jurors_banned(&[]);
+        assert_eq!(result, Ok(()));
     }
 
     #[ink::test]
@@ -962,4 +1649,90 @@ mod tests {
         let result = dispute.assert_dispute_remove();
         assert_eq!(result, Ok(()));
     }
+
+    #[ink::test]
+    fn settlement() {
+        let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+        set_caller::<DefaultEnvironment>(accounts.alice);
+        let mut dispute = Dispute::create(1, "".into(), accounts.bob, 15);
+
+        // Failed, nothing to accept.
+        let result = dispute.accept_settlement();
+        assert_eq!(result, Err(BrightDisputesError::SettlementNotProposed));
+
+        // Failed, invalid split.
+        let result = dispute.propose_settlement(101);
+        assert_eq!(result, Err(BrightDisputesError::InvalidSettlementSplit));
+
+        // Failed, only owner or defendant can propose settlement.
+        set_caller::<DefaultEnvironment>(accounts.charlie);
+        let result = dispute.propose_settlement(50);
+        assert_eq!(result, Err(BrightDisputesError::NotAuthorized));
+
+        // Success
+        set_caller::<DefaultEnvironment>(accounts.alice);
+        let result = dispute.propose_settlement(60);
+        assert_eq!(result, Ok(()));
+
+        // Failed, proposer can not accept its own settlement.
+        let result = dispute.accept_settlement();
+        assert_eq!(result, Err(BrightDisputesError::NotAuthorized));
+
+        // Success
+        set_caller::<DefaultEnvironment>(accounts.bob);
+        let settlement = dispute
+            .accept_settlement()
+            .expect("Failed to accept settlement!");
+        assert_eq!(settlement.proposed_by(), accounts.alice);
+        assert_eq!(settlement.owner_share(), 60);
+        assert_eq!(dispute.state, DisputeState::Closed);
+
+        // Failed, dispute is already closed.
+        let result = dispute.propose_settlement(50);
+        assert_eq!(result, Err(BrightDisputesError::InvalidDisputeState));
+    }
+
+    #[ink::test]
+    fn default_judgment() {
+        let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+        set_caller::<DefaultEnvironment>(accounts.alice);
+        ink::env::test::set_block_timestamp::<DefaultEnvironment>(100);
+        let mut dispute = Dispute::create(1, "".into(), accounts.bob, 15);
+        assert_eq!(dispute.created_at(), 100);
+
+        let rules = DefaultJudgmentRules {
+            response_time: 50,
+            max_escrow: Some(15),
+        };
+
+        // Failed, response deadline not reached.
+        let result = rules.assert_allowed(&dispute, 149);
+        assert_eq!(result, Err(BrightDisputesError::ResponseDeadlineNotReached));
+        let result = rules.assert_allowed(&dispute, 150);
+        assert_eq!(result, Ok(()));
+
+        // Failed, escrow is too high.
+        let rules = DefaultJudgmentRules {
+            response_time: 50,
+            max_escrow: Some(10),
+        };
+        let result = rules.assert_allowed(&dispute, 150);
+        assert_eq!(result, Err(BrightDisputesError::DefaultJudgmentNotAllowed));
+
+        // Failed, only owner can request default judgment.
+        set_caller::<DefaultEnvironment>(accounts.bob);
+        let result = dispute.issue_default_judgment();
+        assert_eq!(result, Err(BrightDisputesError::NotAuthorized));
+
+        // Success
+        set_caller::<DefaultEnvironment>(accounts.alice);
+        let result = dispute.issue_default_judgment();
+        assert_eq!(result, Ok(()));
+        assert_eq!(dispute.state, DisputeState::Ended);
+        assert_eq!(dispute.get_dispute_result(), Some(DisputeResult::Owner));
+
+        // Failed, dispute is not in "Created" state.
+        let result = dispute.issue_default_judgment();
+        assert_eq!(result, Err(BrightDisputesError::InvalidDisputeState));
+    }
 }
@@ -1,10 +1,10 @@
 use ink::{prelude::vec::Vec, primitives::AccountId};
 
 use crate::{
-    dispute::Dispute,
+    dispute::{Dispute, VotingMode},
     error::BrightDisputesError,
     juror::JuriesMap,
-    types::{Result, Timestamp},
+    types::{DisputeId, Result, Timestamp},
 };
 
 #[derive(Clone, Debug, PartialEq, scale::Decode, scale::Encode)]
@@ -17,6 +17,16 @@ pub enum RoundState {
     PickingJuriesAndJudge,
     Voting,
     CountingTheVotes,
+    Revealing,
+}
+
+/// Dispute round notification, contract emits it as an event.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DisputeRoundEvent {
+    JurorAssigned(DisputeId, AccountId),
+    JudgeAssigned(DisputeId, AccountId),
+    ActionRequested(DisputeId, AccountId),
+    StateChanged(DisputeId, RoundState, Timestamp),
 }
 
 #[derive(Clone, Debug, PartialEq, scale::Decode, scale::Encode)]
@@ -35,6 +45,7 @@ impl DisputeRound {
     const PICKING_JURIES_AND_JUDGE_TIME: u64 = 2;
     const VOTING_TIME: u64 = 4;
     const VOTING_COUNTING: u64 = 1;
+    const REVEALING_TIME: u64 = 1;
     const INITIAL_NUMBER_OF_JURIES: u8 = 3;
 
     /// Creates new dispute round.
@@ -46,6 +57,21 @@ impl DisputeRound {
         }
     }
 
+    /// Get dispute round state.
+    pub fn state(&self) -> RoundState {
+        self.state.clone()
+    }
+
+    /// Get number of juries, which take part in the dispute round.
+    pub fn number_of_juries(&self) -> u8 {
+        self.number_of_juries
+    }
+
+    /// Get deadline of the current dispute round state.
+    pub fn state_deadline(&self) -> Timestamp {
+        self.state_deadline
+    }
+
     /// Try to switch to the next dispute round, It also check deadlines.
     pub fn process_dispute_round(
         &mut self,
@@ -70,6 +96,14 @@ impl DisputeRound {
         Ok(())
     }
 
+    // Assert when state is not in "Revealing" state.
+    pub fn assert_if_not_revealing_time(&self) -> Result<()> {
+        if self.state != RoundState::Revealing {
+            return Err(BrightDisputesError::WrongDisputeRoundState);
+        }
+        Ok(())
+    }
+
     // Assert when state is not in "CountingTheVotes" state.
     pub fn assert_if_not_counting_the_votes_time(&self) -> Result<()> {
         if self.state != RoundState::CountingTheVotes {
@@ -91,6 +125,7 @@ impl DisputeRound {
 
                 self.state = RoundState::PickingJuriesAndJudge;
                 self.state_deadline = Self::deadline(now, Self::PICKING_JURIES_AND_JUDGE_TIME);
+                self.emit_state_changed(contract, dispute);
             }
             RoundState::PickingJuriesAndJudge => {
                 self.handle_picking_the_juries(contract, dispute)?;
@@ -101,12 +136,18 @@ impl DisputeRound {
                     let mut juror = contract.get_juror_or_assert(juror_id)?;
                     juror.request_for_action(dispute.id())?;
                     contract.update_juror(juror);
+                    contract.emit_round_event(DisputeRoundEvent::ActionRequested(
+                        dispute.id(),
+                        juror_id,
+                    ));
                 }
 
                 self.state = RoundState::Voting;
                 self.state_deadline = Self::deadline(now, Self::VOTING_TIME);
+                self.emit_state_changed(contract, dispute);
             }
             RoundState::Voting => self.handle_voting(contract, dispute, now)?,
+            RoundState::Revealing => self.handle_revealing(contract, dispute, now)?,
             RoundState::CountingTheVotes => return Err(BrightDisputesError::InvalidAction),
         };
         Ok(())
@@ -132,6 +173,7 @@ impl DisputeRound {
             let mut juror = contract.get_juror_or_assert(juror_id)?;
             dispute.assign_juror(&mut juror)?;
             contract.update_juror(juror);
+            contract.emit_round_event(DisputeRoundEvent::JurorAssigned(dispute.id(), juror_id));
         }
 
         Ok(())
@@ -145,6 +187,9 @@ impl DisputeRound {
         dispute.assert_owner_call()?;
         if self.state != RoundState::AssignJuriesAndJudge {
             return Err(BrightDisputesError::WrongDisputeRoundState);
+        } else if dispute.voting_mode() == VotingMode::CommitReveal {
+            // Votes are counted by the contract, there is no judge.
+            return Ok(());
         } else if dispute.judge().is_none() {
             let mut banned_accounts = dispute.banned();
             banned_accounts.extend([dispute.owner(), dispute.defendant()].to_vec());
@@ -154,6 +199,7 @@ impl DisputeRound {
             let mut juror = contract.get_juror_or_assert(judge_id[0])?;
             dispute.assign_judge(&mut juror)?;
             contract.update_juror(juror);
+            contract.emit_round_event(DisputeRoundEvent::JudgeAssigned(dispute.id(), judge_id[0]));
         }
         Ok(())
     }
@@ -188,6 +234,8 @@ impl DisputeRound {
         dispute.assert_owner_call()?;
         if self.state != RoundState::PickingJuriesAndJudge {
             return Err(BrightDisputesError::WrongDisputeRoundState);
+        } else if dispute.voting_mode() == VotingMode::CommitReveal {
+            return Ok(());
         } else if dispute.judge().is_none() {
             return Err(BrightDisputesError::CanNotSwitchDisputeRound);
         }
@@ -208,7 +256,7 @@ impl DisputeRound {
         dispute.assert_owner_call()?;
         if self.state != RoundState::Voting {
             return Err(BrightDisputesError::WrongDisputeRoundState);
-        } else if dispute.votes().is_empty() {
+        } else if dispute.votes().is_empty() && dispute.abstained_juries().is_empty() {
             return Err(BrightDisputesError::JuriesNotVoted(dispute.juries()));
         }
 
@@ -217,18 +265,68 @@ impl DisputeRound {
             return Err(BrightDisputesError::JuriesNotVoted(not_voted));
         }
 
+        // Request juries to reveal their votes.
+        if dispute.voting_mode() == VotingMode::CommitReveal {
+            for juror_id in dispute.juries() {
+                let mut juror = contract.get_juror_or_assert(juror_id)?;
+                juror.request_for_action(dispute.id())?;
+                contract.update_juror(juror);
+                contract
+                    .emit_round_event(DisputeRoundEvent::ActionRequested(dispute.id(), juror_id));
+            }
+
+            self.state = RoundState::Revealing;
+            self.state_deadline = Self::deadline(timestamp, Self::REVEALING_TIME);
+            self.emit_state_changed(contract, dispute);
+            return Ok(());
+        }
+
         // Request judge to count the votes.
         let judge_id = dispute.judge().unwrap();
         let mut judge = contract.get_juror_or_assert(judge_id)?;
         judge.request_for_action(dispute.id())?;
         contract.update_juror(judge);
+        contract.emit_round_event(DisputeRoundEvent::ActionRequested(dispute.id(), judge_id));
+
+        self.state = RoundState::CountingTheVotes;
+        self.state_deadline = Self::deadline(timestamp, Self::VOTING_COUNTING);
+        self.emit_state_changed(contract, dispute);
+
+        Ok(())
+    }
+
+    fn handle_revealing(
+        &mut self,
+        contract: &mut dyn JuriesMap,
+        dispute: &Dispute,
+        timestamp: Timestamp,
+    ) -> Result<()> {
+        dispute.assert_owner_call()?;
+        if self.state != RoundState::Revealing {
+            return Err(BrightDisputesError::WrongDisputeRoundState);
+        }
+
+        let not_revealed: Vec<AccountId> = dispute.get_not_revealed_juries();
+        if !not_revealed.is_empty() {
+            return Err(BrightDisputesError::JuriesNotRevealed(not_revealed));
+        }
 
+        // Votes are counted on-chain, by the contract.
         self.state = RoundState::CountingTheVotes;
         self.state_deadline = Self::deadline(timestamp, Self::VOTING_COUNTING);
+        self.emit_state_changed(contract, dispute);
 
         Ok(())
     }
 
+    fn emit_state_changed(&self, contract: &mut dyn JuriesMap, dispute: &Dispute) {
+        contract.emit_round_event(DisputeRoundEvent::StateChanged(
+            dispute.id(),
+            self.state.clone(),
+            self.state_deadline,
+        ));
+    }
+
     fn deadline(begin: Timestamp, days: u64) -> Timestamp {
         begin + days * 24 * 3600 * 1000
     }
@@ -248,6 +346,14 @@ pub mod mock {
                 state_deadline,
             }
         }
+
+        pub fn revealing(state_deadline: Timestamp) -> DisputeRound {
+            DisputeRound {
+                state: RoundState::Revealing,
+                number_of_juries: DisputeRound::INITIAL_NUMBER_OF_JURIES,
+                state_deadline,
+            }
+        }
     }
 }
 
@@ -323,6 +429,23 @@ mod tests {
         let result = round.process_dispute_round(&mut juries, &mut dispute, start_timestamp);
         assert_eq!(result, Ok(()));
         assert_eq!(round.state, RoundState::PickingJuriesAndJudge);
+        assert_eq!(
+            juries.events,
+            vec![
+                DisputeRoundEvent::JurorAssigned(1, accounts.charlie),
+                DisputeRoundEvent::JurorAssigned(1, accounts.eve),
+                DisputeRoundEvent::JurorAssigned(1, accounts.frank),
+                DisputeRoundEvent::JudgeAssigned(1, accounts.django),
+                DisputeRoundEvent::StateChanged(
+                    1,
+                    RoundState::PickingJuriesAndJudge,
+                    DisputeRound::deadline(
+                        start_timestamp,
+                        DisputeRound::PICKING_JURIES_AND_JUDGE_TIME
+                    )
+                ),
+            ]
+        );
     }
 
     #[ink::test]
@@ -1,3 +1,4 @@
+#[cfg(feature = "baby-liminal")]
 use baby_liminal_extension::BabyLiminalError;
 use ink::{prelude::vec::Vec, primitives::AccountId};
 use scale::{Decode, Encode};
@@ -22,7 +23,8 @@ pub enum BrightDisputesError {
     JurorNotExist,
     JuriesPoolIsToSmall,
     JuriesNotVoted(Vec<AccountId>),
-    JudgeAlreadyAssignedToDispute,    
+    JudgeAlreadyAssignedToDispute,
+    JurorSuspended,
 
     DisputeRoundDeadlineReached,
     DisputeRoundLimitReached,
@@ -34,10 +36,28 @@ pub enum BrightDisputesError {
 
     NotRegisteredAsJuror,
 
+    SettlementNotProposed,
+    InvalidSettlementSplit,
+
+    ResponseDeadlineNotReached,
+    DefaultJudgmentNotAllowed,
+
+    InvalidVotingMode,
+    VotingModeNotSupported,
+    InvalidVoteReveal,
+    JuriesNotRevealed(Vec<AccountId>),
+    InvalidMajorityThreshold,
+    InvalidVotesHash,
+    EvidenceTooLarge,
+    EvidenceLimitReached,
+    VotePublicKeyReused,
+    InvalidJurorsBanned,
+
     /// Pallet returned an error (through chain extension).
     InkError,
 
     /// Pallet returned an error (through chain extension).
+    #[cfg(feature = "baby-liminal")]
     ChainExtension(BabyLiminalError),
 }
 
@@ -47,6 +67,7 @@ impl From<ink::env::Error> for BrightDisputesError {
     }
 }
 
+#[cfg(feature = "baby-liminal")]
 impl From<BabyLiminalError> for BrightDisputesError {
     fn from(e: BabyLiminalError) -> Self {
         BrightDisputesError::ChainExtension(e)
@@ -0,0 +1,41 @@
+use ink::{prelude::vec::Vec, primitives::AccountId};
+
+use crate::types::PublicKey;
+
+/// Evidence attached by one of the dispute parties, encrypted for a single
+/// juror (or the judge). Data is encrypted with a key derived from the ECDH
+/// shared secret of the author's ephemeral key and the juror's public key.
+#[derive(Clone, Debug, PartialEq, scale::Decode, scale::Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+)]
+pub struct Evidence {
+    author: AccountId,
+    public_key: PublicKey,
+    data: Vec<u8>,
+}
+
+impl Evidence {
+    pub fn create(author: AccountId, public_key: PublicKey, data: Vec<u8>) -> Self {
+        Evidence {
+            author,
+            public_key,
+            data,
+        }
+    }
+
+    pub fn author(&self) -> AccountId {
+        self.author
+    }
+
+    #[allow(dead_code)]
+    pub fn public_key(&self) -> PublicKey {
+        self.public_key.clone()
+    }
+
+    #[allow(dead_code)]
+    pub fn data(&self) -> Vec<u8> {
+        self.data.clone()
+    }
+}
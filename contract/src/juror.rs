@@ -1,6 +1,7 @@
 use ink::{prelude::vec::Vec, primitives::AccountId};
 
 use crate::{
+    dispute_round::DisputeRoundEvent,
     error::BrightDisputesError,
     types::{DisputeId, PublicKey, Result},
 };
@@ -13,6 +14,7 @@ pub trait JuriesMap {
         number: u8,
     ) -> Result<Vec<AccountId>>;
     fn update_juror(&mut self, juror: Juror);
+    fn emit_round_event(&mut self, event: DisputeRoundEvent);
 }
 
 #[derive(Clone, Debug, PartialEq, scale::Decode, scale::Encode)]
@@ -131,6 +133,7 @@ pub mod mock {
     pub struct JuriesMapMock {
         juries_pool: Vec<AccountId>,
         juries: Vec<Juror>,
+        pub events: Vec<DisputeRoundEvent>,
     }
 
     impl JuriesMapMock {
@@ -140,6 +143,7 @@ pub mod mock {
             JuriesMapMock {
                 juries_pool,
                 juries,
+                events: Vec::new(),
             }
         }
 
@@ -148,6 +152,7 @@ pub mod mock {
             JuriesMapMock {
                 juries_pool,
                 juries,
+                events: Vec::new(),
             }
         }
     }
@@ -198,6 +203,10 @@ pub mod mock {
                 self.juries.push(juror);
             }
         }
+
+        fn emit_round_event(&mut self, event: DisputeRoundEvent) {
+            self.events.push(event);
+        }
     }
 }
 
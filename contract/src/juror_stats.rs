@@ -0,0 +1,55 @@
+/// Reputation of the juror, it is kept across the disputes, even when juror
+/// leaves and re-joins the juries pool.
+#[derive(Clone, Debug, Default, PartialEq, scale::Decode, scale::Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+)]
+pub struct JurorStats {
+    cases_served: u32,
+    votes_with_majority: u32,
+    bans: u32,
+    missed_deadlines: u32,
+}
+
+impl JurorStats {
+    #[allow(dead_code)]
+    pub fn cases_served(&self) -> u32 {
+        self.cases_served
+    }
+
+    #[allow(dead_code)]
+    pub fn votes_with_majority(&self) -> u32 {
+        self.votes_with_majority
+    }
+
+    #[allow(dead_code)]
+    pub fn bans(&self) -> u32 {
+        self.bans
+    }
+
+    #[allow(dead_code)]
+    pub fn missed_deadlines(&self) -> u32 {
+        self.missed_deadlines
+    }
+
+    /// Juror / judge participated in the dispute until it ended.
+    pub fn increment_cases_served(&mut self) {
+        self.cases_served = self.cases_served.saturating_add(1);
+    }
+
+    /// Juror voted the same way as the majority of the juries.
+    pub fn increment_votes_with_majority(&mut self) {
+        self.votes_with_majority = self.votes_with_majority.saturating_add(1);
+    }
+
+    /// Juror / judge was moved to the banned list of the dispute.
+    pub fn increment_bans(&mut self) {
+        self.bans = self.bans.saturating_add(1);
+    }
+
+    /// Juror / judge didn't make an action before the deadline.
+    pub fn increment_missed_deadlines(&mut self) {
+        self.missed_deadlines = self.missed_deadlines.saturating_add(1);
+    }
+}
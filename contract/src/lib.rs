@@ -1,20 +1,21 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 
-use baby_liminal_extension::VerificationKeyIdentifier;
+#[cfg(all(
+    feature = "mock-verifier",
+    not(feature = "baby-liminal"),
+    not(any(test, feature = "std"))
+))]
+compile_error!(
+    "The `mock-verifier` feature accepts all proofs, it can be used only in the off-chain tests!"
+);
 
 mod contract;
 mod dispute;
 mod dispute_round;
 mod error;
+mod evidence;
 mod juror;
+mod juror_stats;
 mod types;
+mod verifier;
 mod vote;
-
-const VOTE_VK_IDENTIFIER: VerificationKeyIdentifier =
-    [b'v', b'o', b't', b'e', b'v', b'o', b't', b'e'];
-const VERDICT_POSITIVE_VK_IDENTIFIER: VerificationKeyIdentifier =
-    [b'v', b'e', b'r', b'd', b'i', b'c', b't', b'p'];
-const VERDICT_NEGATIVE_VK_IDENTIFIER: VerificationKeyIdentifier =
-    [b'v', b'e', b'r', b'd', b'i', b'c', b't', b'n'];
-const VERDICT_NONE_VK_IDENTIFIER: VerificationKeyIdentifier =
-    [b'v', b'e', b'r', b'd', b'i', b'c', b't', b'o'];
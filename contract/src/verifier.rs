@@ -0,0 +1,264 @@
+use ink::{prelude::vec::Vec, primitives::AccountId};
+use scale::{Decode, Encode};
+
+#[cfg(any(feature = "baby-liminal", not(feature = "mock-verifier")))]
+use crate::error::BrightDisputesError;
+use crate::types::{Proof, Result, VoteHash};
+
+#[cfg(feature = "baby-liminal")]
+use ark_serialize::CanonicalSerialize;
+#[cfg(feature = "baby-liminal")]
+use ark_std::One;
+#[cfg(feature = "baby-liminal")]
+use baby_liminal_extension::{BabyLiminalExtension, VerificationKeyIdentifier};
+#[cfg(feature = "baby-liminal")]
+use ink::prelude::vec;
+#[cfg(feature = "baby-liminal")]
+use liminal_ark_relations::{
+    disputes::{
+        hash_to_field, make_two_to_one_hash, vote_to_filed, VerdictNegativeRelationWithPublicInput,
+        VerdictNoneRelationWithPublicInput, VerdictPositiveRelationWithPublicInput,
+        VerdictRelation, VoteRelationWithPublicInput, MAX_VOTES_LEN,
+    },
+    environment::CircuitField,
+};
+
+#[derive(Eq, PartialEq, Debug, Decode, Encode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Relation {
+    Vote,
+    VerdictNegative,
+    VerdictNone,
+    VerdictPositive,
+}
+
+/// Verifies proofs of the private voting mode. Contract picks the implementation
+/// with the cargo features:
+/// * `baby-liminal` - proofs are verified by the baby-liminal chain extension,
+/// * `mock-verifier` - all proofs are accepted, only for the off-chain tests
+///   (ignored together with `baby-liminal`),
+/// * none of them - private voting is not supported, juries use the commit-reveal voting.
+pub trait Verifier {
+    /// Check if the private voting can be used.
+    fn is_supported(&self) -> bool;
+    /// Maximum number of votes, which the verdict proof can count.
+    fn max_votes(&self) -> Option<u8>;
+    /// Hash of the encrypted votes, which the verdict proof has to be made for.
+    /// `None` if the verifier doesn't bind the verdict to the votes.
+    fn verdict_votes_hash(&self, votes: &[VoteHash]) -> Option<VoteHash>;
+    fn store_key(&mut self, owner: AccountId, relation: Relation, vk: Vec<u8>) -> Result<()>;
+    fn verify_vote(
+        &mut self,
+        proof: Proof,
+        vote: VoteHash,
+        votes_hash: VoteHash,
+        hash_of_all_votes: VoteHash,
+    ) -> Result<()>;
+    /// Verify the verdict, `relation` is one of the verdict relations.
+    fn verify_verdict(
+        &mut self,
+        proof: Proof,
+        relation: Relation,
+        votes_minimum: u8,
+        votes_maximum: u8,
+        hashed_votes: VoteHash,
+    ) -> Result<()>;
+}
+
+#[cfg(feature = "baby-liminal")]
+const VOTE_VK_IDENTIFIER: VerificationKeyIdentifier =
+    [b'v', b'o', b't', b'e', b'v', b'o', b't', b'e'];
+#[cfg(feature = "baby-liminal")]
+const VERDICT_POSITIVE_VK_IDENTIFIER: VerificationKeyIdentifier =
+    [b'v', b'e', b'r', b'd', b'i', b'c', b't', b'p'];
+#[cfg(feature = "baby-liminal")]
+const VERDICT_NEGATIVE_VK_IDENTIFIER: VerificationKeyIdentifier =
+    [b'v', b'e', b'r', b'd', b'i', b'c', b't', b'n'];
+#[cfg(feature = "baby-liminal")]
+const VERDICT_NONE_VK_IDENTIFIER: VerificationKeyIdentifier =
+    [b'v', b'e', b'r', b'd', b'i', b'c', b't', b'o'];
+
+/// Hash of the encrypted votes, used as the public input of the verdict relations.
+/// Empty slots of the verdict circuit are filled with the zero vote.
+#[cfg(feature = "baby-liminal")]
+pub fn verdict_votes_hash(votes: &[VoteHash]) -> VoteHash {
+    let mut hashed_votes = CircuitField::one();
+    for i in 0..MAX_VOTES_LEN as usize {
+        let vote = match votes.get(i) {
+            Some(vote) => hash_to_field(*vote),
+            None => vote_to_filed(0u8),
+        };
+        hashed_votes = make_two_to_one_hash(vote, hashed_votes);
+    }
+    hashed_votes.0 .0
+}
+
+/// Verifier, which uses the baby-liminal chain extension.
+#[cfg(feature = "baby-liminal")]
+pub struct BabyLiminalVerifier<E: BabyLiminalExtension>(pub E);
+
+#[cfg(feature = "baby-liminal")]
+impl<E: BabyLiminalExtension> BabyLiminalVerifier<E> {
+    fn identifier(relation: &Relation) -> VerificationKeyIdentifier {
+        match relation {
+            Relation::Vote => VOTE_VK_IDENTIFIER,
+            Relation::VerdictNegative => VERDICT_NEGATIVE_VK_IDENTIFIER,
+            Relation::VerdictNone => VERDICT_NONE_VK_IDENTIFIER,
+            Relation::VerdictPositive => VERDICT_POSITIVE_VK_IDENTIFIER,
+        }
+    }
+
+    fn serialize<T: CanonicalSerialize + ?Sized>(t: &T) -> Vec<u8> {
+        let mut bytes = vec![0; t.serialized_size()];
+        t.serialize(&mut bytes[..]).expect("Failed to serialize");
+        bytes.to_vec()
+    }
+}
+
+#[cfg(feature = "baby-liminal")]
+impl<E: BabyLiminalExtension> Verifier for BabyLiminalVerifier<E> {
+    fn is_supported(&self) -> bool {
+        true
+    }
+
+    fn max_votes(&self) -> Option<u8> {
+        Some(MAX_VOTES_LEN as u8)
+    }
+
+    fn verdict_votes_hash(&self, votes: &[VoteHash]) -> Option<VoteHash> {
+        Some(verdict_votes_hash(votes))
+    }
+
+    fn store_key(&mut self, owner: AccountId, relation: Relation, vk: Vec<u8>) -> Result<()> {
+        self.0.store_key(owner, Self::identifier(&relation), vk)?;
+        Ok(())
+    }
+
+    fn verify_vote(
+        &mut self,
+        proof: Proof,
+        vote: VoteHash,
+        votes_hash: VoteHash,
+        hash_of_all_votes: VoteHash,
+    ) -> Result<()> {
+        let input = VoteRelationWithPublicInput::new(vote, votes_hash, hash_of_all_votes)
+            .serialize_public_input();
+
+        self.0.verify(
+            VOTE_VK_IDENTIFIER,
+            proof,
+            Self::serialize::<Vec<CircuitField>>(input.as_ref()),
+        )?;
+        Ok(())
+    }
+
+    fn verify_verdict(
+        &mut self,
+        proof: Proof,
+        relation: Relation,
+        votes_minimum: u8,
+        votes_maximum: u8,
+        hashed_votes: VoteHash,
+    ) -> Result<()> {
+        let input = match relation {
+            Relation::VerdictPositive => VerdictPositiveRelationWithPublicInput::new(
+                votes_minimum,
+                VerdictRelation::Positive as u8,
+                hashed_votes,
+            )
+            .serialize_public_input(),
+            Relation::VerdictNegative => VerdictNegativeRelationWithPublicInput::new(
+                votes_maximum,
+                VerdictRelation::Negative as u8,
+                hashed_votes,
+            )
+            .serialize_public_input(),
+            Relation::VerdictNone => VerdictNoneRelationWithPublicInput::new(
+                votes_minimum,
+                votes_maximum,
+                VerdictRelation::None as u8,
+                hashed_votes,
+            )
+            .serialize_public_input(),
+            Relation::Vote => return Err(BrightDisputesError::InvalidAction),
+        };
+
+        self.0.verify(
+            Self::identifier(&relation),
+            proof,
+            Self::serialize::<Vec<CircuitField>>(input.as_ref()),
+        )?;
+        Ok(())
+    }
+}
+
+/// Verifier, which accepts all proofs. It allows to test the private voting
+/// off-chain, without the baby-liminal chain extension.
+#[cfg(all(feature = "mock-verifier", not(feature = "baby-liminal")))]
+pub struct MockVerifier;
+
+#[cfg(all(feature = "mock-verifier", not(feature = "baby-liminal")))]
+impl Verifier for MockVerifier {
+    fn is_supported(&self) -> bool {
+        true
+    }
+
+    fn max_votes(&self) -> Option<u8> {
+        None
+    }
+
+    /// Blake2 hash of the votes, it stands in for the hash of the verdict relations,
+    /// so the tests can check that the verdict is issued for the recorded votes.
+    fn verdict_votes_hash(&self, votes: &[VoteHash]) -> Option<VoteHash> {
+        let mut hash = [0u8; 32];
+        ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(&votes, &mut hash);
+        let mut votes_hash = [0u64; 4];
+        for (word, bytes) in votes_hash.iter_mut().zip(hash.chunks_exact(8)) {
+            *word = u64::from_le_bytes(bytes.try_into().expect("Chunk has 8 bytes"));
+        }
+        Some(votes_hash)
+    }
+
+    fn store_key(&mut self, _: AccountId, _: Relation, _: Vec<u8>) -> Result<()> {
+        Ok(())
+    }
+
+    fn verify_vote(&mut self, _: Proof, _: VoteHash, _: VoteHash, _: VoteHash) -> Result<()> {
+        Ok(())
+    }
+
+    fn verify_verdict(&mut self, _: Proof, _: Relation, _: u8, _: u8, _: VoteHash) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Verifier used when the private voting is not supported, it rejects all proofs.
+#[cfg(not(any(feature = "baby-liminal", feature = "mock-verifier")))]
+pub struct NoVerifier;
+
+#[cfg(not(any(feature = "baby-liminal", feature = "mock-verifier")))]
+impl Verifier for NoVerifier {
+    fn is_supported(&self) -> bool {
+        false
+    }
+
+    fn max_votes(&self) -> Option<u8> {
+        None
+    }
+
+    fn verdict_votes_hash(&self, _: &[VoteHash]) -> Option<VoteHash> {
+        None
+    }
+
+    fn store_key(&mut self, _: AccountId, _: Relation, _: Vec<u8>) -> Result<()> {
+        Err(BrightDisputesError::VotingModeNotSupported)
+    }
+
+    fn verify_vote(&mut self, _: Proof, _: VoteHash, _: VoteHash, _: VoteHash) -> Result<()> {
+        Err(BrightDisputesError::VotingModeNotSupported)
+    }
+
+    fn verify_verdict(&mut self, _: Proof, _: Relation, _: u8, _: u8, _: VoteHash) -> Result<()> {
+        Err(BrightDisputesError::VotingModeNotSupported)
+    }
+}
@@ -1,6 +1,6 @@
-use ink::primitives::AccountId;
+use ink::{env::hash::Blake2x256, primitives::AccountId};
 
-use crate::types::VoteHash;
+use crate::types::{PublicKey, VoteHash};
 #[derive(Clone, Debug, PartialEq, scale::Decode, scale::Encode)]
 #[cfg_attr(
     feature = "std",
@@ -9,12 +9,31 @@ use crate::types::VoteHash;
 pub struct Vote {
     juror: AccountId,
     vote: VoteHash,
+    public_key: Option<PublicKey>,
 }
 
 impl Vote {
+    /// Vote of the juror, who can't decide (commit-reveal voting mode). It is not
+    /// counted to the majority and the juror is never banned for it.
+    pub const ABSTAIN: u8 = 2;
+
     #[allow(dead_code)]
     pub fn create(juror: AccountId, vote: VoteHash) -> Self {
-        Vote { juror, vote }
+        Vote {
+            juror,
+            vote,
+            public_key: None,
+        }
+    }
+
+    /// Changed vote of the juror (private voting mode), encrypted with the fresh key
+    /// instead of the one given when the juror confirmed the participation.
+    pub fn create_with_public_key(juror: AccountId, vote: VoteHash, public_key: PublicKey) -> Self {
+        Vote {
+            juror,
+            vote,
+            public_key: Some(public_key),
+        }
     }
     pub fn juror(&self) -> AccountId {
         self.juror
@@ -22,4 +41,22 @@ impl Vote {
     pub fn vote(&self) -> VoteHash {
         self.vote
     }
+
+    /// Public key the vote is encrypted with, if it isn't the juror one.
+    pub fn public_key(&self) -> Option<PublicKey> {
+        self.public_key.clone()
+    }
+
+    /// Commitment of the juror vote, used in the commit-reveal voting mode.
+    /// It is a blake2 hash of the scale encoded `(juror, vote, salt)`.
+    pub fn commitment(juror: AccountId, vote: u8, salt: [u8; 32]) -> VoteHash {
+        let mut hash = [0u8; 32];
+        ink::env::hash_encoded::<Blake2x256, _>(&(juror, vote, salt), &mut hash);
+
+        let mut commitment = [0u64; 4];
+        for (i, chunk) in hash.chunks(8).enumerate() {
+            commitment[i] = u64::from_le_bytes(chunk.try_into().unwrap());
+        }
+        commitment
+    }
 }
@@ -123,7 +123,7 @@ build() {
     cd "${SCRIPT_DIR}"/..
 
     log_progress "Start bulding smart contract..."
-    docker_ink_dev "cargo contract build --release --manifest-path contract/Cargo.toml 1>/dev/null"
+    docker_ink_dev "cargo contract build --release --manifest-path contract/Cargo.toml --features baby-liminal 1>/dev/null"
     log_progress "✅ Contract was built"
 }
 
@@ -12,6 +12,14 @@ pub const CODE_HASH: [u8; 32] = [
 pub struct Vote {
     pub juror: ink_primitives::AccountId,
     pub vote: [u64; 4],
+    pub public_key: Option<Vec<u8>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+pub struct Evidence {
+    pub author: ink_primitives::AccountId,
+    pub public_key: Vec<u8>,
+    pub data: Vec<u8>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
@@ -22,6 +30,7 @@ pub struct Dispute {
     pub owner_link: String,
     pub escrow: u128,
     pub deposit: u128,
+    pub created_at: u64,
     pub defendant: ink_primitives::AccountId,
     pub defendant_link: Option<String>,
     pub dispute_result: Option<DisputeResult>,
@@ -32,6 +41,28 @@ pub struct Dispute {
     pub banned: Vec<ink_primitives::AccountId>,
     pub votes: Vec<Vote>,
     pub votes_hash: [u64; 4],
+    pub settlement: Option<Settlement>,
+    pub voting_mode: VotingMode,
+    pub revealed_votes: Vec<(ink_primitives::AccountId, u8)>,
+    pub majority_threshold: MajorityThreshold,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+pub struct Settlement {
+    pub proposed_by: ink_primitives::AccountId,
+    pub owner_share: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+pub enum VotingMode {
+    Private(),
+    CommitReveal(),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+pub struct MajorityThreshold {
+    pub numerator: u32,
+    pub denominator: u32,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
@@ -61,6 +92,7 @@ pub enum RoundState {
     PickingJuriesAndJudge(),
     Voting(),
     CountingTheVotes(),
+    Revealing(),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
@@ -82,6 +114,7 @@ pub enum BrightDisputesError {
     JuriesPoolIsToSmall(),
     JuriesNotVoted(Vec<ink_primitives::AccountId>),
     JudgeAlreadyAssignedToDispute(),
+    JurorSuspended(),
     DisputeRoundDeadlineReached(),
     DisputeRoundLimitReached(),
     DisputeRoundNotStarted(),
@@ -89,6 +122,20 @@ pub enum BrightDisputesError {
     CanNotSwitchDisputeRound(),
     MajorityOfVotesNotReached(),
     NotRegisteredAsJuror(),
+    SettlementNotProposed(),
+    InvalidSettlementSplit(),
+    ResponseDeadlineNotReached(),
+    DefaultJudgmentNotAllowed(),
+    InvalidVotingMode(),
+    VotingModeNotSupported(),
+    InvalidVoteReveal(),
+    JuriesNotRevealed(Vec<ink_primitives::AccountId>),
+    InvalidMajorityThreshold(),
+    InvalidVotesHash(),
+    EvidenceTooLarge(),
+    EvidenceLimitReached(),
+    VotePublicKeyReused(),
+    InvalidJurorsBanned(),
     InkError(),
     ChainExtension(BabyLiminalError),
 }
@@ -122,6 +169,40 @@ pub enum Relation {
     VerdictPositive(),
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+pub enum ActionKind {
+    ConfirmDefendant(),
+    ConfirmJuror(),
+    ConfirmJudge(),
+    Vote(),
+    CountTheVotes(),
+    ProcessDisputeRound(),
+    RequestDefaultJudgment(),
+    RevealVote(),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+pub struct DefaultJudgmentRules {
+    pub response_time: u64,
+    pub max_escrow: Option<u128>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+pub struct DisputeFilter {
+    pub state: Option<DisputeState>,
+    pub owner: Option<ink_primitives::AccountId>,
+    pub defendant: Option<ink_primitives::AccountId>,
+    pub juror: Option<ink_primitives::AccountId>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+pub struct JurorStats {
+    pub cases_served: u32,
+    pub votes_with_majority: u32,
+    pub bans: u32,
+    pub missed_deadlines: u32,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
 pub struct Extension();
 
@@ -146,7 +227,93 @@ pub mod event {
 
         DisputeResultEvent {
             id: u32,
-            result: super::DisputeResult,
+            result: Option<super::DisputeResult>,
+        },
+
+        JurorAssigned {
+            dispute_id: u32,
+            juror_id: ink_primitives::AccountId,
+        },
+
+        JudgeAssigned {
+            dispute_id: u32,
+            judge_id: ink_primitives::AccountId,
+        },
+
+        JurorConfirmed {
+            dispute_id: u32,
+            juror_id: ink_primitives::AccountId,
+        },
+
+        JudgeConfirmed {
+            dispute_id: u32,
+            judge_id: ink_primitives::AccountId,
+        },
+
+        ActionRequested {
+            dispute_id: u32,
+            juror_id: ink_primitives::AccountId,
+        },
+
+        VoteCast {
+            dispute_id: u32,
+            juror_id: ink_primitives::AccountId,
+        },
+
+        VotesCounted {
+            dispute_id: u32,
+            judge_id: ink_primitives::AccountId,
+            verdict: super::Verdict,
+        },
+
+        RoundStateChanged {
+            dispute_id: u32,
+            state: super::RoundState,
+            deadline: u64,
+        },
+
+        RoundDeadlineReached {
+            dispute_id: u32,
+            state: super::RoundState,
+        },
+
+        JurorBanned {
+            dispute_id: u32,
+            juror_id: ink_primitives::AccountId,
+        },
+
+        DepositPaid {
+            dispute_id: u32,
+            account_id: ink_primitives::AccountId,
+            amount: u128,
+        },
+
+        SettlementProposed {
+            dispute_id: u32,
+            proposed_by: ink_primitives::AccountId,
+            owner_share: u8,
+        },
+
+        DisputeSettled {
+            dispute_id: u32,
+            owner_share: u8,
+        },
+
+        DefaultJudgmentIssued {
+            dispute_id: u32,
+            owner_id: ink_primitives::AccountId,
+            defendant_id: ink_primitives::AccountId,
+        },
+
+        JurorSuspended {
+            juror_id: ink_primitives::AccountId,
+            suspended_until: u64,
+        },
+
+        VoteRevealed {
+            dispute_id: u32,
+            juror_id: ink_primitives::AccountId,
+            vote: u8,
         },
     }
 }
@@ -179,13 +346,48 @@ pub fn upload() -> ink_wrapper_types::UploadCall {
 }
 
 impl Instance {
-    /// Constructor
+    ///  Constructor, default judgments are disabled.
     #[allow(dead_code, clippy::too_many_arguments)]
     pub fn new() -> ink_wrapper_types::InstantiateCall<Self> {
         let data = vec![155, 174, 157, 94];
         ink_wrapper_types::InstantiateCall::new(CODE_HASH, data)
     }
 
+    ///  Constructor with custom suspension rules. Juror is suspended after every
+    ///  `suspension_bans_limit` bans. If `suspension_time` is `None`, the
+    ///  suspension is permanent. Default judgments are disabled.
+    #[allow(dead_code, clippy::too_many_arguments)]
+    pub fn new_with_suspension_rules(
+        suspension_bans_limit: u32,
+        suspension_time: Option<u64>,
+    ) -> ink_wrapper_types::InstantiateCall<Self> {
+        let data = {
+            let mut data = vec![16, 199, 193, 89];
+            suspension_bans_limit.encode_to(&mut data);
+            suspension_time.encode_to(&mut data);
+            data
+        };
+        ink_wrapper_types::InstantiateCall::new(CODE_HASH, data)
+    }
+
+    ///  Constructor with custom suspension and default judgment rules. If
+    ///  `default_judgment_rules` is `None`, default judgments are disabled.
+    #[allow(dead_code, clippy::too_many_arguments)]
+    pub fn new_with_rules(
+        suspension_bans_limit: u32,
+        suspension_time: Option<u64>,
+        default_judgment_rules: Option<DefaultJudgmentRules>,
+    ) -> ink_wrapper_types::InstantiateCall<Self> {
+        let data = {
+            let mut data = vec![142, 123, 246, 237];
+            suspension_bans_limit.encode_to(&mut data);
+            suspension_time.encode_to(&mut data);
+            default_judgment_rules.encode_to(&mut data);
+            data
+        };
+        ink_wrapper_types::InstantiateCall::new(CODE_HASH, data)
+    }
+
     ///  Get last dispute id
     #[allow(dead_code, clippy::too_many_arguments)]
     pub fn get_last_dispute_id(
@@ -220,6 +422,58 @@ impl Instance {
         ink_wrapper_types::ReadCall::new(self.account_id, data)
     }
 
+    ///  Get page of disputes, which meet the filter conditions. `offset` is
+    ///  the number of matching disputes to skip, `limit` is capped at
+    ///  `DISPUTES_PAGE_LIMIT`. Filtering by an account uses the per account
+    ///  index, so it doesn't scan all disputes.
+    #[allow(dead_code, clippy::too_many_arguments)]
+    pub fn get_disputes(
+        &self,
+        offset: u32,
+        limit: u32,
+        filter: DisputeFilter,
+    ) -> ink_wrapper_types::ReadCall<Result<Vec<Dispute>, ink_wrapper_types::InkLangError>> {
+        let data = {
+            let mut data = vec![62, 143, 91, 196];
+            offset.encode_to(&mut data);
+            limit.encode_to(&mut data);
+            filter.encode_to(&mut data);
+            data
+        };
+        ink_wrapper_types::ReadCall::new(self.account_id, data)
+    }
+
+    ///  Get ids of disputes, where account is an owner, defendant, judge or juror.
+    #[allow(dead_code, clippy::too_many_arguments)]
+    pub fn get_account_disputes(
+        &self,
+        account_id: ink_primitives::AccountId,
+    ) -> ink_wrapper_types::ReadCall<Result<Vec<u32>, ink_wrapper_types::InkLangError>> {
+        let data = {
+            let mut data = vec![169, 192, 209, 132];
+            account_id.encode_to(&mut data);
+            data
+        };
+        ink_wrapper_types::ReadCall::new(self.account_id, data)
+    }
+
+    ///  Get actions, which account needs to take in its disputes, together
+    ///  with the deadline of the current dispute round.
+    #[allow(dead_code, clippy::too_many_arguments)]
+    pub fn pending_actions(
+        &self,
+        account_id: ink_primitives::AccountId,
+    ) -> ink_wrapper_types::ReadCall<
+        Result<Vec<(u32, ActionKind, Option<u64>)>, ink_wrapper_types::InkLangError>,
+    > {
+        let data = {
+            let mut data = vec![170, 46, 226, 89];
+            account_id.encode_to(&mut data);
+            data
+        };
+        ink_wrapper_types::ReadCall::new(self.account_id, data)
+    }
+
     ///  Get juries pool
     #[allow(dead_code, clippy::too_many_arguments)]
     pub fn get_juries_pool(
@@ -231,7 +485,36 @@ impl Instance {
         ink_wrapper_types::ReadCall::new(self.account_id, data)
     }
 
-    ///  Get single dispute by id
+    ///  Get reputation of the juror, collected across all disputes.
+    #[allow(dead_code, clippy::too_many_arguments)]
+    pub fn get_juror_stats(
+        &self,
+        juror_id: ink_primitives::AccountId,
+    ) -> ink_wrapper_types::ReadCall<Result<JurorStats, ink_wrapper_types::InkLangError>> {
+        let data = {
+            let mut data = vec![241, 233, 234, 198];
+            juror_id.encode_to(&mut data);
+            data
+        };
+        ink_wrapper_types::ReadCall::new(self.account_id, data)
+    }
+
+    ///  Get the time until juror is suspended from registering as an active
+    ///  juror. Permanent suspension lasts until `Timestamp::MAX`.
+    #[allow(dead_code, clippy::too_many_arguments)]
+    pub fn get_juror_suspension(
+        &self,
+        juror_id: ink_primitives::AccountId,
+    ) -> ink_wrapper_types::ReadCall<Result<Option<u64>, ink_wrapper_types::InkLangError>> {
+        let data = {
+            let mut data = vec![53, 145, 232, 73];
+            juror_id.encode_to(&mut data);
+            data
+        };
+        ink_wrapper_types::ReadCall::new(self.account_id, data)
+    }
+
+    ///  Remove single dispute by id
     #[allow(dead_code, clippy::too_many_arguments)]
     pub fn remove_dispute(&self, dispute_id: u32) -> ink_wrapper_types::ExecCall {
         let data = {
@@ -260,6 +543,29 @@ impl Instance {
         ink_wrapper_types::ExecCallNeedsValue::new(self.account_id, data)
     }
 
+    ///  Create new dispute with the selected voting mode. If `majority_threshold`
+    ///  is `None`, 70% of the votes are needed to reach the majority.
+    #[allow(dead_code, clippy::too_many_arguments)]
+    pub fn create_dispute_with_voting_mode(
+        &self,
+        owner_link: String,
+        defendant_id: ink_primitives::AccountId,
+        escrow: u128,
+        voting_mode: VotingMode,
+        majority_threshold: Option<MajorityThreshold>,
+    ) -> ink_wrapper_types::ExecCallNeedsValue {
+        let data = {
+            let mut data = vec![136, 123, 118, 7];
+            owner_link.encode_to(&mut data);
+            defendant_id.encode_to(&mut data);
+            escrow.encode_to(&mut data);
+            voting_mode.encode_to(&mut data);
+            majority_threshold.encode_to(&mut data);
+            data
+        };
+        ink_wrapper_types::ExecCallNeedsValue::new(self.account_id, data)
+    }
+
     ///  Defendant confirms his participation in dispute.
     #[allow(dead_code, clippy::too_many_arguments)]
     pub fn confirm_defendant(
@@ -276,6 +582,19 @@ impl Instance {
         ink_wrapper_types::ExecCallNeedsValue::new(self.account_id, data)
     }
 
+    ///  Owner requests the default judgment, when the defendant hasn't
+    ///  confirmed the dispute before the response deadline. Dispute ends
+    ///  in favour of the owner without a jury and the escrow is returned.
+    #[allow(dead_code, clippy::too_many_arguments)]
+    pub fn request_default_judgment(&self, dispute_id: u32) -> ink_wrapper_types::ExecCall {
+        let data = {
+            let mut data = vec![50, 119, 106, 81];
+            dispute_id.encode_to(&mut data);
+            data
+        };
+        ink_wrapper_types::ExecCall::new(self.account_id, data)
+    }
+
     ///  Update owner link description
     #[allow(dead_code, clippy::too_many_arguments)]
     pub fn update_owner_description(
@@ -308,7 +627,7 @@ impl Instance {
         ink_wrapper_types::ExecCall::new(self.account_id, data)
     }
 
-    ///  Voting, only juror can do it.
+    ///  Voting, only juror can do it. The vote can be changed with `change_vote`.
     #[allow(dead_code, clippy::too_many_arguments)]
     pub fn vote(
         &self,
@@ -328,6 +647,79 @@ impl Instance {
         ink_wrapper_types::ExecCall::new(self.account_id, data)
     }
 
+    ///  Abstain from the vote (private voting mode), only juror who hasn't voted can do it.
+    ///  Juror is not counted to the majority, is never banned for it and gets
+    ///  `ABSTAIN_FEE_PERCENT` of the regular deposit share. Unlike the vote, abstain is public.
+    #[allow(dead_code, clippy::too_many_arguments)]
+    pub fn abstain(&self, dispute_id: u32) -> ink_wrapper_types::ExecCall {
+        let data = {
+            let mut data = vec![178, 212, 161, 206];
+            dispute_id.encode_to(&mut data);
+            data
+        };
+        ink_wrapper_types::ExecCall::new(self.account_id, data)
+    }
+
+    ///  Change the vote (private voting mode), only juror who has voted can do it, until
+    ///  the voting ends. The vote is encrypted with a fresh `public_key`, which the judge
+    ///  uses to decrypt it. With the key of the previous vote the difference of the
+    ///  encrypted votes would show if the vote has changed.
+    #[allow(dead_code, clippy::too_many_arguments)]
+    pub fn change_vote(
+        &self,
+        dispute_id: u32,
+        public_key: Vec<u8>,
+        vote: [u64; 4],
+        hash_of_all_votes: [u64; 4],
+        proof: Vec<u8>,
+    ) -> ink_wrapper_types::ExecCall {
+        let data = {
+            let mut data = vec![212, 32, 99, 64];
+            dispute_id.encode_to(&mut data);
+            public_key.encode_to(&mut data);
+            vote.encode_to(&mut data);
+            hash_of_all_votes.encode_to(&mut data);
+            proof.encode_to(&mut data);
+            data
+        };
+        ink_wrapper_types::ExecCall::new(self.account_id, data)
+    }
+
+    ///  Commit the hash of the vote (commit-reveal voting mode), only juror can do it.
+    ///  `commitment` is a blake2 hash of the scale encoded `(juror, vote, salt)`.
+    #[allow(dead_code, clippy::too_many_arguments)]
+    pub fn commit_vote(
+        &self,
+        dispute_id: u32,
+        commitment: [u64; 4],
+    ) -> ink_wrapper_types::ExecCall {
+        let data = {
+            let mut data = vec![33, 62, 171, 248];
+            dispute_id.encode_to(&mut data);
+            commitment.encode_to(&mut data);
+            data
+        };
+        ink_wrapper_types::ExecCall::new(self.account_id, data)
+    }
+
+    ///  Reveal the committed vote (commit-reveal voting mode), only juror can do it.
+    #[allow(dead_code, clippy::too_many_arguments)]
+    pub fn reveal_vote(
+        &self,
+        dispute_id: u32,
+        vote: u8,
+        salt: [u8; 32],
+    ) -> ink_wrapper_types::ExecCall {
+        let data = {
+            let mut data = vec![42, 30, 75, 78];
+            dispute_id.encode_to(&mut data);
+            vote.encode_to(&mut data);
+            salt.encode_to(&mut data);
+            data
+        };
+        ink_wrapper_types::ExecCall::new(self.account_id, data)
+    }
+
     ///  Register as an active juror. Juries are picked
     ///  from this pool to participate in disputes.
     #[allow(dead_code, clippy::too_many_arguments)]
@@ -375,6 +767,30 @@ impl Instance {
         ink_wrapper_types::ExecCallNeedsValue::new(self.account_id, data)
     }
 
+    ///  Get thresholds of the verdict for the recorded votes (private voting mode):
+    ///  `(votes_minimum, votes_maximum)`.
+    #[allow(dead_code, clippy::too_many_arguments)]
+    pub fn get_verdict_thresholds(
+        &self,
+        dispute_id: u32,
+    ) -> ink_wrapper_types::ReadCall<
+        Result<Result<(u8, u8), BrightDisputesError>, ink_wrapper_types::InkLangError>,
+    > {
+        let data = {
+            let mut data = vec![213, 170, 13, 5];
+            dispute_id.encode_to(&mut data);
+            data
+        };
+        ink_wrapper_types::ReadCall::new(self.account_id, data)
+    }
+
+    ///  Issue the verdict proven by the judge (private voting mode). Single votes
+    ///  are not known on-chain, so the judge lists the juries who voted against
+    ///  the verdict (`jurors_banned`) and proves it with two proofs: `proof` shows
+    ///  that the rest of the votes agree with the verdict and `minority_proof` that
+    ///  the votes of banned juries are against it. For the verdict `None` nobody is
+    ///  banned and `proof` counts all votes. The list is as trustworthy as the
+    ///  verdict itself, the judge can't add juries to it or leave them out.
     #[allow(dead_code, clippy::too_many_arguments)]
     pub fn issue_the_verdict(
         &self,
@@ -383,8 +799,9 @@ impl Instance {
         votes_minimum: u8,
         verdict: Verdict,
         hashed_votes: [u64; 4],
-        jurors_banned: Vec<ink_primitives::AccountId>,
         proof: Vec<u8>,
+        jurors_banned: Vec<ink_primitives::AccountId>,
+        minority_proof: Vec<u8>,
     ) -> ink_wrapper_types::ExecCall {
         let data = {
             let mut data = vec![6, 210, 102, 105];
@@ -393,8 +810,9 @@ impl Instance {
             votes_minimum.encode_to(&mut data);
             verdict.encode_to(&mut data);
             hashed_votes.encode_to(&mut data);
-            jurors_banned.encode_to(&mut data);
             proof.encode_to(&mut data);
+            jurors_banned.encode_to(&mut data);
+            minority_proof.encode_to(&mut data);
             data
         };
         ink_wrapper_types::ExecCall::new(self.account_id, data)
@@ -411,6 +829,39 @@ impl Instance {
         ink_wrapper_types::ExecCall::new(self.account_id, data)
     }
 
+    ///  Propose the settlement of the dispute (call by owner or defendant).
+    ///  `owner_share` is the percentage of the parties funds, which goes to
+    ///  the owner, the rest goes to the defendant.
+    #[allow(dead_code, clippy::too_many_arguments)]
+    pub fn propose_settlement(
+        &self,
+        dispute_id: u32,
+        owner_share: u8,
+    ) -> ink_wrapper_types::ExecCall {
+        let data = {
+            let mut data = vec![120, 46, 113, 34];
+            dispute_id.encode_to(&mut data);
+            owner_share.encode_to(&mut data);
+            data
+        };
+        ink_wrapper_types::ExecCall::new(self.account_id, data)
+    }
+
+    ///  Accept the settlement proposed by the other party. Dispute is closed,
+    ///  juries and judge are released back to the pool. Confirmed juries and
+    ///  judge get back their escrow and share a fee taken from the parties
+    ///  funds, the rest is split between the parties. If the defendant hasn't
+    ///  confirmed the dispute, only the owner has deposited and gets it back.
+    #[allow(dead_code, clippy::too_many_arguments)]
+    pub fn accept_settlement(&self, dispute_id: u32) -> ink_wrapper_types::ExecCall {
+        let data = {
+            let mut data = vec![236, 2, 193, 110];
+            dispute_id.encode_to(&mut data);
+            data
+        };
+        ink_wrapper_types::ExecCall::new(self.account_id, data)
+    }
+
     ///  Register a verification key.
     #[allow(dead_code, clippy::too_many_arguments)]
     pub fn register_vk(&self, relation: Relation, vk: Vec<u8>) -> ink_wrapper_types::ExecCall {
@@ -440,4 +891,60 @@ impl Instance {
         };
         ink_wrapper_types::ReadCall::new(self.account_id, data)
     }
+
+    ///  Check if the Juror/Judge has confirmed participation in the dispute. Public key of
+    ///  the juror can be left from the previous dispute, so it is not a confirmation.
+    #[allow(dead_code, clippy::too_many_arguments)]
+    pub fn has_confirmed_participation(
+        &self,
+        dispute_id: u32,
+        juror_id: ink_primitives::AccountId,
+    ) -> ink_wrapper_types::ReadCall<
+        Result<Result<bool, BrightDisputesError>, ink_wrapper_types::InkLangError>,
+    > {
+        let data = {
+            let mut data = vec![4, 50, 171, 186];
+            dispute_id.encode_to(&mut data);
+            juror_id.encode_to(&mut data);
+            data
+        };
+        ink_wrapper_types::ReadCall::new(self.account_id, data)
+    }
+
+    ///  Attach evidence encrypted for the jurors / judge of the dispute.
+    ///  Only the owner or the defendant can do it. Evidence can be
+    ///  attached only for accounts which confirmed participation in the
+    ///  dispute and stored their public key.
+    #[allow(dead_code, clippy::too_many_arguments)]
+    pub fn submit_evidence(
+        &self,
+        dispute_id: u32,
+        public_key: Vec<u8>,
+        evidences: Vec<(ink_primitives::AccountId, Vec<u8>)>,
+    ) -> ink_wrapper_types::ExecCall {
+        let data = {
+            let mut data = vec![70, 48, 240, 236];
+            dispute_id.encode_to(&mut data);
+            public_key.encode_to(&mut data);
+            evidences.encode_to(&mut data);
+            data
+        };
+        ink_wrapper_types::ExecCall::new(self.account_id, data)
+    }
+
+    ///  Get evidence encrypted for the juror / judge of the dispute.
+    #[allow(dead_code, clippy::too_many_arguments)]
+    pub fn get_evidence(
+        &self,
+        dispute_id: u32,
+        juror_id: ink_primitives::AccountId,
+    ) -> ink_wrapper_types::ReadCall<Result<Vec<Evidence>, ink_wrapper_types::InkLangError>> {
+        let data = {
+            let mut data = vec![43, 171, 127, 103];
+            dispute_id.encode_to(&mut data);
+            juror_id.encode_to(&mut data);
+            data
+        };
+        ink_wrapper_types::ReadCall::new(self.account_id, data)
+    }
 }
@@ -4,7 +4,9 @@ use core::iter::zip;
 use ink_wrapper_types::{util::ToAccountId, Connection as _, SignedConnection as _};
 use rand::RngCore as _;
 
-use bright_disputes_lib::{prepare_counting_inputs, prepare_voting_inputs, PublicVote};
+use bright_disputes_lib::{
+    generate_ecdh_keys, prepare_counting_inputs, prepare_voting_inputs, PublicVote,
+};
 use liminal_ark_relations::disputes::VerdictRelation;
 use std::path::PathBuf;
 
@@ -251,23 +253,28 @@ async fn test_dispute_verdict_positive() -> Result<()> {
         .map(|(index, &ref vote)| {
             let key = setup.jurors.get(index).unwrap();
             PublicVote {
-                id: vote.juror.clone(),
+                id: vote.juror,
                 pub_key: key.public.clone(),
                 hashed_vote: vote.vote,
             }
         })
         .collect();
 
-    let (votes_maximum, votes_minimum, verdict, hashed_votes, jurors_banned, proof) =
-        prepare_counting_inputs(
-            setup.judge.private.clone(),
-            votes,
-            &setup.verdict_none_pk,
-            &setup.verdict_negative_pk,
-            &setup.verdict_positive_pk,
-        )?;
-
-    let ink_verdict = match verdict {
+    let (votes_minimum, votes_maximum) = owner_conn
+        .read(contract.get_verdict_thresholds(dispute_id))
+        .await??
+        .expect("Unable to get verdict thresholds!");
+    let inputs = prepare_counting_inputs(
+        setup.judge.private.clone(),
+        votes,
+        votes_minimum,
+        votes_maximum,
+        &setup.verdict_none_pk,
+        &setup.verdict_negative_pk,
+        &setup.verdict_positive_pk,
+    )?;
+
+    let ink_verdict = match inputs.verdict {
         VerdictRelation::Positive => Verdict::Positive(),
         VerdictRelation::Negative => Verdict::Negative(),
         VerdictRelation::None => Verdict::None(),
@@ -279,9 +286,10 @@ async fn test_dispute_verdict_positive() -> Result<()> {
             votes_maximum,
             votes_minimum,
             ink_verdict,
-            hashed_votes,
-            jurors_banned,
-            proof,
+            inputs.hashed_votes,
+            inputs.proof,
+            inputs.jurors_banned,
+            inputs.minority_proof,
         ))
         .await?;
 
@@ -404,6 +412,31 @@ async fn test_dispute_verdict_negative() -> Result<()> {
             .await?;
     }
 
+    // First juror votes by mistake for the owner and changes the vote back, the changed
+    // vote is encrypted with a fresh key.
+    for vote in [1u8, 0u8] {
+        let (public_key, private_key) = generate_ecdh_keys();
+        let (encrypted_vote, new_encrypted_all_votes, proof) = prepare_voting_inputs(
+            vote,
+            votes_hash,
+            setup.judge.public.clone(),
+            private_key,
+            &setup.vote_pk,
+        )?;
+
+        votes_hash = new_encrypted_all_votes.clone();
+
+        juries_conn[0]
+            .exec(contract.change_vote(
+                dispute_id,
+                public_key,
+                encrypted_vote,
+                new_encrypted_all_votes,
+                proof,
+            ))
+            .await?;
+    }
+
     // Process dispute round, check if all juries votes and move to counting the votes
     owner_conn
         .exec(contract.process_dispute_round(dispute_id))
@@ -422,23 +455,28 @@ async fn test_dispute_verdict_negative() -> Result<()> {
         .map(|(index, &ref vote)| {
             let key = setup.jurors.get(index).unwrap();
             PublicVote {
-                id: vote.juror.clone(),
-                pub_key: key.public.clone(),
+                id: vote.juror,
+                pub_key: vote.public_key.clone().unwrap_or(key.public.clone()),
                 hashed_vote: vote.vote,
             }
         })
         .collect();
 
-    let (votes_maximum, votes_minimum, verdict, hashed_votes, jurors_banned, proof) =
-        prepare_counting_inputs(
-            setup.judge.private.clone(),
-            votes,
-            &setup.verdict_none_pk,
-            &setup.verdict_negative_pk,
-            &setup.verdict_positive_pk,
-        )?;
-
-    let ink_verdict = match verdict {
+    let (votes_minimum, votes_maximum) = owner_conn
+        .read(contract.get_verdict_thresholds(dispute_id))
+        .await??
+        .expect("Unable to get verdict thresholds!");
+    let inputs = prepare_counting_inputs(
+        setup.judge.private.clone(),
+        votes,
+        votes_minimum,
+        votes_maximum,
+        &setup.verdict_none_pk,
+        &setup.verdict_negative_pk,
+        &setup.verdict_positive_pk,
+    )?;
+
+    let ink_verdict = match inputs.verdict {
         VerdictRelation::Positive => Verdict::Positive(),
         VerdictRelation::Negative => Verdict::Negative(),
         VerdictRelation::None => Verdict::None(),
@@ -450,9 +488,10 @@ async fn test_dispute_verdict_negative() -> Result<()> {
             votes_maximum,
             votes_minimum,
             ink_verdict,
-            hashed_votes,
-            jurors_banned,
-            proof,
+            inputs.hashed_votes,
+            inputs.proof,
+            inputs.jurors_banned,
+            inputs.minority_proof,
         ))
         .await?;
 
@@ -594,23 +633,28 @@ async fn test_dispute_verdict_none() -> Result<()> {
         .map(|(index, &ref vote)| {
             let key = setup.jurors.get(index).unwrap();
             PublicVote {
-                id: vote.juror.clone(),
+                id: vote.juror,
                 pub_key: key.public.clone(),
                 hashed_vote: vote.vote,
             }
         })
         .collect();
 
-    let (votes_maximum, votes_minimum, verdict, hashed_votes, jurors_banned, proof) =
-        prepare_counting_inputs(
-            setup.judge.private.clone(),
-            votes,
-            &setup.verdict_none_pk,
-            &setup.verdict_negative_pk,
-            &setup.verdict_positive_pk,
-        )?;
-
-    let ink_verdict = match verdict {
+    let (votes_minimum, votes_maximum) = owner_conn
+        .read(contract.get_verdict_thresholds(dispute_id))
+        .await??
+        .expect("Unable to get verdict thresholds!");
+    let inputs = prepare_counting_inputs(
+        setup.judge.private.clone(),
+        votes,
+        votes_minimum,
+        votes_maximum,
+        &setup.verdict_none_pk,
+        &setup.verdict_negative_pk,
+        &setup.verdict_positive_pk,
+    )?;
+
+    let ink_verdict = match inputs.verdict {
         VerdictRelation::Positive => Verdict::Positive(),
         VerdictRelation::Negative => Verdict::Negative(),
         VerdictRelation::None => Verdict::None(),
@@ -622,9 +666,10 @@ async fn test_dispute_verdict_none() -> Result<()> {
             votes_maximum,
             votes_minimum,
             ink_verdict,
-            hashed_votes,
-            jurors_banned,
-            proof,
+            inputs.hashed_votes,
+            inputs.proof,
+            inputs.jurors_banned,
+            inputs.minority_proof,
         ))
         .await?;
 
@@ -778,23 +823,28 @@ async fn test_dispute_rounds() -> Result<()> {
         .map(|(index, &ref vote)| {
             let key = setup.jurors.get(index).unwrap();
             PublicVote {
-                id: vote.juror.clone(),
+                id: vote.juror,
                 pub_key: key.public.clone(),
                 hashed_vote: vote.vote,
             }
         })
         .collect();
 
-    let (votes_maximum, votes_minimum, verdict, hashed_votes, jurors_banned, proof) =
-        prepare_counting_inputs(
-            setup.judge.private.clone(),
-            votes,
-            &setup.verdict_none_pk,
-            &setup.verdict_negative_pk,
-            &setup.verdict_positive_pk,
-        )?;
-
-    let ink_verdict = match verdict {
+    let (votes_minimum, votes_maximum) = owner_conn
+        .read(contract.get_verdict_thresholds(dispute_id))
+        .await??
+        .expect("Unable to get verdict thresholds!");
+    let inputs = prepare_counting_inputs(
+        setup.judge.private.clone(),
+        votes,
+        votes_minimum,
+        votes_maximum,
+        &setup.verdict_none_pk,
+        &setup.verdict_negative_pk,
+        &setup.verdict_positive_pk,
+    )?;
+
+    let ink_verdict = match inputs.verdict {
         VerdictRelation::Positive => Verdict::Positive(),
         VerdictRelation::Negative => Verdict::Negative(),
         VerdictRelation::None => Verdict::None(),
@@ -806,9 +856,10 @@ async fn test_dispute_rounds() -> Result<()> {
             votes_maximum,
             votes_minimum,
             ink_verdict,
-            hashed_votes,
-            jurors_banned,
-            proof,
+            inputs.hashed_votes,
+            inputs.proof,
+            inputs.jurors_banned,
+            inputs.minority_proof,
         ))
         .await?;
 
@@ -910,23 +961,28 @@ async fn test_dispute_rounds() -> Result<()> {
         .map(|(index, &ref vote)| {
             let key = setup.jurors_large.get(index).unwrap();
             PublicVote {
-                id: vote.juror.clone(),
+                id: vote.juror,
                 pub_key: key.public.clone(),
                 hashed_vote: vote.vote,
             }
         })
         .collect();
 
-    let (votes_maximum, votes_minimum, verdict, hashed_votes, jurors_banned, proof) =
-        prepare_counting_inputs(
-            setup.judge.private.clone(),
-            votes,
-            &setup.verdict_none_pk,
-            &setup.verdict_negative_pk,
-            &setup.verdict_positive_pk,
-        )?;
-
-    let ink_verdict = match verdict {
+    let (votes_minimum, votes_maximum) = owner_conn
+        .read(contract.get_verdict_thresholds(dispute_id))
+        .await??
+        .expect("Unable to get verdict thresholds!");
+    let inputs = prepare_counting_inputs(
+        setup.judge.private.clone(),
+        votes,
+        votes_minimum,
+        votes_maximum,
+        &setup.verdict_none_pk,
+        &setup.verdict_negative_pk,
+        &setup.verdict_positive_pk,
+    )?;
+
+    let ink_verdict = match inputs.verdict {
         VerdictRelation::Positive => Verdict::Positive(),
         VerdictRelation::Negative => Verdict::Negative(),
         VerdictRelation::None => Verdict::None(),
@@ -938,9 +994,10 @@ async fn test_dispute_rounds() -> Result<()> {
             votes_maximum,
             votes_minimum,
             ink_verdict,
-            hashed_votes,
-            jurors_banned,
-            proof,
+            inputs.hashed_votes,
+            inputs.proof,
+            inputs.jurors_banned,
+            inputs.minority_proof,
         ))
         .await?;
 